@@ -8,23 +8,59 @@ enabling efficient encoding and decoding of ThingWorx AlwaysOn protocol messages
 from ._native import (
     AlwaysOnError,
     BaseType,
+    DataShape,
+    DataShapeEntry,
     InfoTable,
+    Location,
+    MessageDecoder,
+    MessageEncoder,
+    ThingWorxCodecError,
+    ThingWorxProtocolError,
+    ThingWorxSchemaError,
     TwPrim,
     TwxEvent,
     TwxMessage,
     TwxProperty,
     TwxService,
     __version__,
+    decode_message,
+    decode_twprim,
+    deserialize_prims,
+    get_version,
+    iter_base_types,
+    make_auth_message,
+    parse_multiple_messages,
+    serialize_prims,
+    split_frames,
+    supported_base_types,
 )
 
 __all__ = [
     "AlwaysOnError",
     "BaseType",
+    "DataShape",
+    "DataShapeEntry",
     "InfoTable",
+    "Location",
+    "MessageDecoder",
+    "MessageEncoder",
+    "ThingWorxCodecError",
+    "ThingWorxProtocolError",
+    "ThingWorxSchemaError",
     "TwPrim",
     "TwxEvent",
     "TwxMessage",
     "TwxProperty",
     "TwxService",
     "__version__",
+    "decode_message",
+    "decode_twprim",
+    "deserialize_prims",
+    "get_version",
+    "iter_base_types",
+    "make_auth_message",
+    "parse_multiple_messages",
+    "serialize_prims",
+    "split_frames",
+    "supported_base_types",
 ]
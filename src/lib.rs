@@ -4,16 +4,176 @@
 
 mod types;
 
+use alwayson_codec::{
+    message::tw_message::TwxMsg as RustTwxMsg, primitive::TwPrim as RustTwPrim, BytesStream,
+};
+use bytes::BytesMut;
 use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyIterator};
+use types::base_type::{base_type_code, parse_base_type, BASE_TYPE_CODES};
 use types::{
-    PyAlwaysOnError, PyBaseType, PyInfoTable, PyTwPrim, PyTwxEvent, PyTwxMessage, PyTwxProperty,
-    PyTwxService,
+    PyAlwaysOnError, PyBaseType, PyDataShape, PyDataShapeEntry, PyInfoTable, PyLocation,
+    PyMessageDecoder, PyMessageEncoder, PyTwPrim, PyTwxEvent, PyTwxMessage, PyTwxProperty,
+    PyTwxService, ThingWorxCodecError, ThingWorxProtocolError, ThingWorxSchemaError,
 };
 
+/// Package version, taken from Cargo.toml so it cannot drift from the build metadata
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Returns the package version, same as `__version__`
+#[pyfunction]
+fn get_version() -> &'static str {
+    VERSION
+}
+
+/// Decodes the message at the start of `data`, returning it with its frame length
+fn next_frame(data: &[u8]) -> PyResult<(RustTwxMsg, usize)> {
+    match RustTwxMsg::from_bytes(data) {
+        Ok((msg, consumed)) if consumed > 0 => Ok((msg, consumed)),
+        _ => Err(ThingWorxProtocolError::new_err(format!(
+            "{} trailing bytes could not be parsed as a message",
+            data.len()
+        ))),
+    }
+}
+
+/// Decodes every complete message in a buffer of concatenated frames
+#[pyfunction]
+fn parse_multiple_messages(data: &[u8]) -> PyResult<Vec<PyTwxMessage>> {
+    let mut messages = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        let (msg, consumed) = next_frame(&data[offset..])?;
+        messages.push(PyTwxMessage { inner: msg });
+        offset += consumed;
+    }
+    Ok(messages)
+}
+
+/// Splits a buffer of concatenated frames into the bytes of each message.
+///
+/// The header carries no length field, so each body is walked to find where the
+/// next frame starts; the messages themselves are discarded.
+#[pyfunction]
+fn split_frames<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Vec<Bound<'py, PyBytes>>> {
+    let mut frames = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        let (_msg, consumed) = next_frame(&data[offset..])?;
+        frames.push(PyBytes::new_bound(py, &data[offset..offset + consumed]));
+        offset += consumed;
+    }
+    Ok(frames)
+}
+
+/// Decodes a single message, same as `TwxMessage.from_bytes`
+#[pyfunction]
+fn decode_message(data: &[u8]) -> PyResult<PyTwxMessage> {
+    PyTwxMessage::from_bytes(data)
+}
+
+/// Builds an auth message, same as `TwxMessage.build_auth`
+#[pyfunction]
+fn make_auth_message(request_id: u32, app_key: String) -> PyResult<PyTwxMessage> {
+    PyTwxMessage::build_auth(request_id, app_key)
+}
+
+/// Decodes a value whose base type is known from metadata. `data` is the encoded
+/// value without the leading type byte that `TwPrim.to_bytes` writes, and must hold
+/// exactly one value.
+#[pyfunction]
+fn decode_twprim(base_type: &str, data: &[u8]) -> PyResult<PyTwPrim> {
+    let mut content = Vec::with_capacity(data.len() + 1);
+    content.push(base_type_code(&parse_base_type(base_type)?));
+    content.extend_from_slice(data);
+    let (prim, consumed) = RustTwPrim::from_bytes(&content).map_err(|e| {
+        ThingWorxCodecError::new_err(format!("Binary deserialization error: {}", e))
+    })?;
+    if consumed < content.len() {
+        return Err(ThingWorxCodecError::new_err(format!(
+            "{} bytes left after decoding a {base_type} value",
+            content.len() - consumed
+        )));
+    }
+    Ok(PyTwPrim { inner: prim })
+}
+
+/// Encodes primitives back to back, as `b"".join(p.to_bytes() for p in prims)` would
+#[pyfunction]
+fn serialize_prims<'py>(
+    py: Python<'py>,
+    prims: Vec<PyRef<'py, PyTwPrim>>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let mut content = BytesMut::new();
+    for prim in &prims {
+        prim.inner.to_bytes(&mut content).map_err(|e| {
+            ThingWorxCodecError::new_err(format!("Binary serialization error: {}", e))
+        })?;
+    }
+    Ok(PyBytes::new_bound(py, &content))
+}
+
+/// Decodes primitives written back to back by `serialize_prims`, checking each value
+/// against the expected base type at the same position
+#[pyfunction]
+fn deserialize_prims(base_types: Vec<String>, data: &[u8]) -> PyResult<Vec<PyTwPrim>> {
+    let mut prims = Vec::with_capacity(base_types.len());
+    let mut offset = 0;
+    for (index, base_type) in base_types.iter().enumerate() {
+        let expected = parse_base_type(base_type)?;
+        let Some(&type_byte) = data.get(offset) else {
+            return Err(ThingWorxCodecError::new_err(format!(
+                "Data ended after {index} of {} values",
+                base_types.len()
+            )));
+        };
+        if type_byte != base_type_code(&expected) {
+            return Err(ThingWorxCodecError::new_err(format!(
+                "Value {index} has type code {type_byte:#04x}, expected {expected:?}"
+            )));
+        }
+        let (prim, consumed) = RustTwPrim::from_bytes(&data[offset..]).map_err(|e| {
+            ThingWorxCodecError::new_err(format!(
+                "Binary deserialization error in value {index}: {}",
+                e
+            ))
+        })?;
+        prims.push(PyTwPrim { inner: prim });
+        offset += consumed;
+    }
+    if offset < data.len() {
+        return Err(ThingWorxCodecError::new_err(format!(
+            "{} bytes left after decoding {} values",
+            data.len() - offset,
+            base_types.len()
+        )));
+    }
+    Ok(prims)
+}
+
+/// Iterates over every base type, matching `BaseType.all_types()`.
+///
+/// PyO3 classes cannot define a metaclass, so `for bt in BaseType` is not available.
+#[pyfunction]
+fn iter_base_types(py: Python<'_>) -> PyResult<Bound<'_, PyIterator>> {
+    py.get_type_bound::<PyBaseType>()
+        .call_method0("all_types")?
+        .iter()
+}
+
+/// Lists the names of every supported base type, as accepted by `BaseType(name)`
+#[pyfunction]
+fn supported_base_types() -> Vec<String> {
+    BASE_TYPE_CODES
+        .iter()
+        .map(|(base_type, _)| format!("{:?}", base_type))
+        .collect()
+}
+
 /// Python bindings for ThingWorx AlwaysOn protocol codec
 #[pymodule]
-fn _native<'py>(_py: Python<'py>, m: &Bound<'py, PyModule>) -> PyResult<()> {
-    m.setattr("__version__", "0.6.0")?;
+fn _native<'py>(py: Python<'py>, m: &Bound<'py, PyModule>) -> PyResult<()> {
+    m.setattr("__version__", VERSION)?;
 
     m.add_class::<PyBaseType>()?;
     m.add_class::<PyTwPrim>()?;
@@ -22,7 +182,35 @@ fn _native<'py>(_py: Python<'py>, m: &Bound<'py, PyModule>) -> PyResult<()> {
     m.add_class::<PyTwxService>()?;
     m.add_class::<PyTwxProperty>()?;
     m.add_class::<PyInfoTable>()?;
+    m.add_class::<PyLocation>()?;
+    m.add_class::<PyMessageDecoder>()?;
+    m.add_class::<PyMessageEncoder>()?;
+    m.add_class::<PyDataShape>()?;
+    m.add_class::<PyDataShapeEntry>()?;
     m.add_class::<PyAlwaysOnError>()?;
+    m.add(
+        "ThingWorxCodecError",
+        py.get_type_bound::<ThingWorxCodecError>(),
+    )?;
+    m.add(
+        "ThingWorxProtocolError",
+        py.get_type_bound::<ThingWorxProtocolError>(),
+    )?;
+    m.add(
+        "ThingWorxSchemaError",
+        py.get_type_bound::<ThingWorxSchemaError>(),
+    )?;
+
+    m.add_function(wrap_pyfunction!(parse_multiple_messages, m)?)?;
+    m.add_function(wrap_pyfunction!(serialize_prims, m)?)?;
+    m.add_function(wrap_pyfunction!(split_frames, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_message, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_twprim, m)?)?;
+    m.add_function(wrap_pyfunction!(deserialize_prims, m)?)?;
+    m.add_function(wrap_pyfunction!(get_version, m)?)?;
+    m.add_function(wrap_pyfunction!(make_auth_message, m)?)?;
+    m.add_function(wrap_pyfunction!(iter_base_types, m)?)?;
+    m.add_function(wrap_pyfunction!(supported_base_types, m)?)?;
 
     Ok(())
 }
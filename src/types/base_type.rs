@@ -1,6 +1,35 @@
-use alwayson_codec::base::BaseType as RustBaseType;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use alwayson_codec::{
+    base::BaseType as RustBaseType, datashape::DataShape as RustDataShape,
+    infotable::InfoTable as RustInfoTable, primitive::TwPrim as RustTwPrim,
+};
+use bytes::Bytes;
+use indexmap::IndexMap;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::{PyList, PyType};
+
+use super::error::ThingWorxSchemaError;
+use super::location::PyLocation;
+use super::primitive::PyTwPrim;
+use super::wire::NOTHING_TYPE;
+
+/// Base types with their AlwaysOn wire-format codes.
+pub(crate) const BASE_TYPE_CODES: [(RustBaseType, u8); 11] = [
+    (RustBaseType::NOTHING, NOTHING_TYPE),
+    (RustBaseType::STRING, 0),
+    (RustBaseType::NUMBER, 1),
+    (RustBaseType::BOOLEAN, 2),
+    (RustBaseType::DATETIME, 3),
+    (RustBaseType::INFOTABLE, 5),
+    (RustBaseType::LOCATION, 6),
+    (RustBaseType::VARIANT, 18),
+    (RustBaseType::BLOB, 21),
+    (RustBaseType::INTEGER, 22),
+    (RustBaseType::LONG, 23),
+];
 
 #[pyclass(name = "BaseType")]
 #[derive(Clone, Debug)]
@@ -12,24 +41,38 @@ pub struct PyBaseType {
 impl PyBaseType {
     #[new]
     fn new(type_name: &str) -> PyResult<Self> {
-        let base_type = match type_name.to_uppercase().as_str() {
-            "BOOLEAN" => RustBaseType::BOOLEAN,
-            "INTEGER" => RustBaseType::INTEGER,
-            "LONG" => RustBaseType::LONG,
-            "NUMBER" => RustBaseType::NUMBER,
-            "STRING" => RustBaseType::STRING,
-            "DATETIME" => RustBaseType::DATETIME,
-            "BLOB" => RustBaseType::BLOB,
-            "LOCATION" => RustBaseType::LOCATION,
-            "INFOTABLE" => RustBaseType::INFOTABLE,
-            "VARIANT" => RustBaseType::VARIANT,
-            _ => {
-                return Err(PyValueError::new_err(format!(
-                    "Invalid base type: {type_name}"
-                )))
-            }
-        };
-        Ok(PyBaseType { inner: base_type })
+        Ok(PyBaseType {
+            inner: parse_base_type(type_name)?,
+        })
+    }
+
+    #[staticmethod]
+    fn from_discriminant(code: i64) -> PyResult<Self> {
+        BASE_TYPE_CODES
+            .iter()
+            .find(|(_, candidate)| i64::from(*candidate) == code)
+            .map(|(base_type, _)| PyBaseType { inner: *base_type })
+            .ok_or_else(|| {
+                let valid: Vec<String> = BASE_TYPE_CODES
+                    .iter()
+                    .map(|(_, code)| code.to_string())
+                    .collect();
+                PyValueError::new_err(format!(
+                    "Unknown base type code {code}; valid codes are {}",
+                    valid.join(", ")
+                ))
+            })
+    }
+
+    /// Returns one instance of every base type, including NOTHING.
+    #[classmethod]
+    fn all_types<'py>(cls: &Bound<'py, PyType>) -> PyResult<Bound<'py, PyList>> {
+        let py = cls.py();
+        let types = BASE_TYPE_CODES
+            .iter()
+            .map(|(base_type, _)| Py::new(py, PyBaseType { inner: *base_type }))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, types))
     }
 
     #[classattr]
@@ -52,6 +95,85 @@ impl PyBaseType {
     const INFOTABLE: &'static str = "INFOTABLE";
     #[classattr]
     const VARIANT: &'static str = "VARIANT";
+    #[classattr]
+    const NOTHING: &'static str = "NOTHING";
+
+    fn is_numeric(&self) -> bool {
+        matches!(
+            self.inner,
+            RustBaseType::INTEGER | RustBaseType::LONG | RustBaseType::NUMBER
+        )
+    }
+
+    /// True for types holding a single value; INFOTABLE, VARIANT and NOTHING are not scalar.
+    fn is_scalar(&self) -> bool {
+        !matches!(
+            self.inner,
+            RustBaseType::INFOTABLE | RustBaseType::VARIANT | RustBaseType::NOTHING
+        )
+    }
+
+    /// Returns the "zero" value of this type, e.g. `False`, `0`, `""` or an empty InfoTable.
+    fn default_value(&self) -> PyTwPrim {
+        let bt = self.inner;
+        let prim = match bt {
+            RustBaseType::BOOLEAN => RustTwPrim::BOOLEAN(bt, false),
+            RustBaseType::INTEGER => RustTwPrim::INTEGER(bt, 0),
+            RustBaseType::LONG => RustTwPrim::LONG(bt, 0),
+            RustBaseType::NUMBER => RustTwPrim::NUMBER(bt, 0.0),
+            RustBaseType::STRING => RustTwPrim::STRING(bt, String::new()),
+            RustBaseType::DATETIME => RustTwPrim::DATETIME(bt, 0),
+            RustBaseType::BLOB => RustTwPrim::BLOB(bt, Bytes::new()),
+            RustBaseType::LOCATION => PyLocation::new(0.0, 0.0, 0.0).to_prim(),
+            RustBaseType::INFOTABLE => {
+                let infotable = RustInfoTable {
+                    datashape: RustDataShape {
+                        name: None,
+                        entries: IndexMap::new(),
+                    },
+                    rows: Vec::new(),
+                };
+                RustTwPrim::INFOTABLE(bt, Box::new(infotable))
+            }
+            // NOTHING and VARIANT have no meaningful zero value
+            _ => RustTwPrim::NOTHING(RustBaseType::NOTHING),
+        };
+        PyTwPrim { inner: prim }
+    }
+
+    /// True if a value of this type can be stored in a field declared as `other` without
+    /// data loss: identical types, INTEGER widening to LONG or NUMBER, and anything in a
+    /// VARIANT. LONG does not widen to NUMBER since doubles cannot hold every 64-bit value.
+    fn is_compatible_with(&self, other: &Self) -> bool {
+        self.inner == other.inner
+            || other.inner == RustBaseType::VARIANT
+            || matches!(
+                (self.inner, other.inner),
+                (
+                    RustBaseType::INTEGER,
+                    RustBaseType::LONG | RustBaseType::NUMBER
+                )
+            )
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __ne__(&self, other: &Self) -> bool {
+        self.inner != other.inner
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        std::mem::discriminant(&self.inner).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the code identifying this type in the AlwaysOn binary format.
+    fn __int__(&self) -> u8 {
+        base_type_code(&self.inner)
+    }
 
     fn __str__(&self) -> String {
         format!("{:?}", self.inner)
@@ -61,3 +183,41 @@ impl PyBaseType {
         format!("BaseType.{:?}", self.inner)
     }
 }
+
+/// Parses a base type name such as `"NUMBER"` (case-insensitive).
+pub(crate) fn parse_base_type(type_name: &str) -> PyResult<RustBaseType> {
+    match type_name.to_uppercase().as_str() {
+        "BOOLEAN" => Ok(RustBaseType::BOOLEAN),
+        "INTEGER" => Ok(RustBaseType::INTEGER),
+        "LONG" => Ok(RustBaseType::LONG),
+        "NUMBER" => Ok(RustBaseType::NUMBER),
+        "STRING" => Ok(RustBaseType::STRING),
+        "DATETIME" => Ok(RustBaseType::DATETIME),
+        "BLOB" => Ok(RustBaseType::BLOB),
+        "LOCATION" => Ok(RustBaseType::LOCATION),
+        "INFOTABLE" => Ok(RustBaseType::INFOTABLE),
+        "VARIANT" => Ok(RustBaseType::VARIANT),
+        "NOTHING" => Ok(RustBaseType::NOTHING),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid base type: {type_name}"
+        ))),
+    }
+}
+
+/// Like `parse_base_type`, but rejects `NOTHING`, which no field can hold a value of.
+pub(crate) fn parse_field_type(type_name: &str) -> PyResult<RustBaseType> {
+    match parse_base_type(type_name)? {
+        RustBaseType::NOTHING => Err(ThingWorxSchemaError::new_err(
+            "NOTHING is not a valid field type",
+        )),
+        base_type => Ok(base_type),
+    }
+}
+
+pub(crate) fn base_type_code(base_type: &RustBaseType) -> u8 {
+    BASE_TYPE_CODES
+        .iter()
+        .find(|(candidate, _)| candidate == base_type)
+        .map(|(_, code)| *code)
+        .unwrap_or(NOTHING_TYPE)
+}
@@ -0,0 +1,245 @@
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyIterator, PyList};
+
+use alwayson_codec::datashape::{DataShape as RustDataShape, DataShapeEntry};
+use indexmap::IndexMap;
+
+use super::base_type::parse_field_type;
+use super::entities::{aspect_text, aspect_value};
+use super::error::ThingWorxSchemaError;
+
+#[pyclass(name = "DataShape")]
+#[derive(Clone, Debug)]
+pub struct PyDataShape {
+    pub(crate) inner: RustDataShape,
+}
+
+#[pymethods]
+impl PyDataShape {
+    #[new]
+    #[pyo3(signature = (name=None))]
+    fn new(name: Option<String>) -> Self {
+        PyDataShape {
+            inner: RustDataShape {
+                name,
+                entries: IndexMap::new(),
+            },
+        }
+    }
+
+    #[staticmethod]
+    fn from_json(json_str: &str) -> PyResult<Self> {
+        match serde_json::from_str::<RustDataShape>(json_str) {
+            Ok(datashape) => Ok(PyDataShape { inner: datashape }),
+            Err(e) => Err(PyValueError::new_err(format!(
+                "DataShape JSON deserialization error: {}",
+                e
+            ))),
+        }
+    }
+
+    fn get_name(&self) -> Option<String> {
+        self.inner.name.clone()
+    }
+
+    fn get_entry_names(&self) -> Vec<String> {
+        self.inner.entries.keys().cloned().collect()
+    }
+
+    fn get_entry_count(&self) -> usize {
+        self.inner.entries.len()
+    }
+
+    fn get_entry(&self, name: &str) -> PyResult<PyDataShapeEntry> {
+        self.inner
+            .entries
+            .get(name)
+            .map(|entry| PyDataShapeEntry {
+                inner: entry.clone(),
+            })
+            .ok_or_else(|| PyKeyError::new_err(name.to_string()))
+    }
+
+    fn get_entries(&self) -> Vec<PyDataShapeEntry> {
+        self.inner
+            .entries
+            .values()
+            .map(|entry| PyDataShapeEntry {
+                inner: entry.clone(),
+            })
+            .collect()
+    }
+
+    /// Stores `entry` under its name, replacing an existing field in place or appending
+    /// a new one. This is how changes made to an entry from `get_entry` are written back.
+    fn set_entry(&mut self, entry: &PyDataShapeEntry) {
+        self.inner
+            .entries
+            .insert(entry.inner.name.clone(), entry.inner.clone());
+    }
+
+    fn add_entry(&mut self, name: String, base_type: &str, description: String) -> PyResult<()> {
+        if self.inner.entries.contains_key(&name) {
+            return Err(ThingWorxSchemaError::new_err(format!(
+                "Field already defined: {name}"
+            )));
+        }
+
+        let entry = DataShapeEntry {
+            name: name.clone(),
+            description,
+            entry_type: parse_field_type(base_type)?,
+            aspects: Default::default(),
+        };
+        self.inner.entries.insert(name, entry);
+        Ok(())
+    }
+
+    fn remove_entry(&mut self, name: &str) -> PyResult<()> {
+        // shift_remove keeps the remaining fields in their original order
+        self.inner
+            .entries
+            .shift_remove(name)
+            .map(|_| ())
+            .ok_or_else(|| PyKeyError::new_err(name.to_string()))
+    }
+
+    /// Returns a new data shape with this shape's fields followed by any fields only
+    /// `other` defines. Fields present in both must have the same base type.
+    fn merge(&self, other: &PyDataShape) -> PyResult<Self> {
+        let mut merged = self.inner.clone();
+        for (name, entry) in &other.inner.entries {
+            match merged.entries.get(name) {
+                Some(existing) if existing.entry_type != entry.entry_type => {
+                    return Err(ThingWorxSchemaError::new_err(format!(
+                        "Conflicting types for field {name}: {:?} and {:?}",
+                        existing.entry_type, entry.entry_type
+                    )));
+                }
+                Some(_) => {}
+                None => {
+                    merged.entries.insert(name.clone(), entry.clone());
+                }
+            }
+        }
+        Ok(PyDataShape { inner: merged })
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.inner)
+            .map_err(|e| PyValueError::new_err(format!("JSON serialization error: {e}")))
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.entries.len()
+    }
+
+    fn __contains__(&self, name: &str) -> bool {
+        self.inner.entries.contains_key(name)
+    }
+
+    /// Iterates over a snapshot of the field names in definition order.
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new_bound(py, self.inner.entries.keys())
+            .as_any()
+            .iter()
+    }
+
+    /// Shapes are equal when they define equal entries (see `DataShapeEntry.__eq__`) in the
+    /// same order. The shape name is not compared.
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner.entries.len() == other.inner.entries.len()
+            && self
+                .inner
+                .entries
+                .values()
+                .zip(other.inner.entries.values())
+                .all(|(entry, other_entry)| entries_equal(entry, other_entry))
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "DataShape(name={:?}, fields={})",
+            self.inner.name,
+            self.inner.entries.len()
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
+
+#[pyclass(name = "DataShapeEntry")]
+#[derive(Clone, Debug)]
+pub struct PyDataShapeEntry {
+    pub(crate) inner: DataShapeEntry,
+}
+
+#[pymethods]
+impl PyDataShapeEntry {
+    fn get_name(&self) -> String {
+        self.inner.name.clone()
+    }
+
+    fn get_base_type(&self) -> String {
+        format!("{:?}", self.inner.entry_type)
+    }
+
+    fn get_description(&self) -> String {
+        self.inner.description.clone()
+    }
+
+    /// Returns the entry aspects (e.g. `isPrimaryKey`) as string values.
+    fn get_aspects<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        for (key, value) in &self.inner.aspects {
+            dict.set_item(key, aspect_text(value))?;
+        }
+        Ok(dict)
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("name", &self.inner.name)?;
+        dict.set_item("base_type", self.get_base_type())?;
+        dict.set_item("description", &self.inner.description)?;
+        dict.set_item("aspects", self.get_aspects(py)?)?;
+        Ok(dict)
+    }
+
+    fn get_aspect(&self, key: &str) -> Option<String> {
+        self.inner.aspects.get(key).map(aspect_text)
+    }
+
+    /// Inserts or replaces an aspect. `value` is text as `get_aspect` returns it, so `"true"`
+    /// or `"0"` are stored as JSON scalars. Entries returned by `DataShape.get_entry` are
+    /// copies; pass the entry to `DataShape.set_entry` to store the change.
+    fn set_aspect(&mut self, key: String, value: &str) {
+        self.inner.aspects.insert(key, aspect_value(value));
+    }
+
+    /// Entries are equal when name, base type, description and aspects all match.
+    fn __eq__(&self, other: &Self) -> bool {
+        entries_equal(&self.inner, &other.inner)
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "DataShapeEntry(name={}, type={:?})",
+            self.inner.name, self.inner.entry_type
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
+
+fn entries_equal(entry: &DataShapeEntry, other: &DataShapeEntry) -> bool {
+    entry.name == other.name
+        && entry.entry_type == other.entry_type
+        && entry.description == other.description
+        && entry.aspects == other.aspects
+}
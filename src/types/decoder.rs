@@ -0,0 +1,108 @@
+use pyo3::prelude::*;
+
+use alwayson_codec::{message::tw_message::TwxMsg as RustTwxMsg, BytesStream};
+
+use super::error::ThingWorxProtocolError;
+use super::message::PyTwxMessage;
+use super::wire::{HEADER_SIZE, PROTOCOL_VERSION};
+
+/// Largest frame `MessageDecoder` waits for by default (16 MiB).
+const DEFAULT_MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+/// Reassembles messages from chunks of a byte stream.
+///
+/// Frames carry no length field, so a buffered frame is only known to be complete once
+/// it decodes. A frame is treated as corrupt, raising `ThingWorxProtocolError`, when its
+/// header has the wrong protocol version or when `max_frame_size` bytes are buffered
+/// and it still does not decode.
+///
+/// This has two costs. Each `feed` decodes the pending frame again from its start, so a
+/// large frame arriving in many small chunks takes time quadratic in its size. And a
+/// corrupt frame with a valid version byte looks like an incomplete one, so it holds back
+/// every later message until `max_frame_size` bytes arrive; lower the limit to fail
+/// sooner on links that only carry small frames.
+#[pyclass(name = "MessageDecoder")]
+#[derive(Debug)]
+pub struct PyMessageDecoder {
+    buffer: Vec<u8>,
+    max_frame_size: usize,
+}
+
+#[pymethods]
+impl PyMessageDecoder {
+    #[new]
+    #[pyo3(signature = (max_frame_size=DEFAULT_MAX_FRAME_SIZE))]
+    fn new(max_frame_size: usize) -> Self {
+        PyMessageDecoder {
+            buffer: Vec::new(),
+            max_frame_size,
+        }
+    }
+
+    /// Buffers `data` and returns every message completed by it, in stream order.
+    ///
+    /// Messages decoded before a corrupt frame are still returned; the next call raises.
+    /// Raising discards the buffer, since the stream can no longer be followed.
+    fn feed(&mut self, data: &[u8]) -> PyResult<Vec<PyTwxMessage>> {
+        self.buffer.extend_from_slice(data);
+
+        let mut messages = Vec::new();
+        let mut offset = 0;
+        let result = loop {
+            let pending = &self.buffer[offset..];
+            if pending.len() < HEADER_SIZE {
+                break Ok(());
+            }
+            if pending[0] != PROTOCOL_VERSION {
+                break Err(ThingWorxProtocolError::new_err(format!(
+                    "Unexpected protocol version {:#04x} at the start of a frame",
+                    pending[0]
+                )));
+            }
+            match RustTwxMsg::from_bytes(pending) {
+                Ok((msg, consumed)) if consumed > 0 => {
+                    messages.push(PyTwxMessage { inner: msg });
+                    offset += consumed;
+                }
+                Ok(_) => {
+                    break Err(ThingWorxProtocolError::new_err(
+                        "Frame decoded without consuming any bytes",
+                    ))
+                }
+                Err(e) if pending.len() >= self.max_frame_size => {
+                    break Err(ThingWorxProtocolError::new_err(format!(
+                        "No message in {} buffered bytes: {}",
+                        pending.len(),
+                        e
+                    )))
+                }
+                // Incomplete frame, wait for the rest
+                Err(_) => break Ok(()),
+            }
+        };
+        self.buffer.drain(..offset);
+
+        match result {
+            Err(_) if !messages.is_empty() => Ok(messages),
+            Err(e) => {
+                self.buffer.clear();
+                Err(e)
+            }
+            Ok(()) => Ok(messages),
+        }
+    }
+
+    /// Number of buffered bytes not yet decoded into a message.
+    fn pending_bytes(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Drops any buffered bytes, e.g. after reconnecting.
+    fn reset(&mut self) {
+        self.buffer.clear();
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MessageDecoder(buffered={})", self.buffer.len())
+    }
+}
@@ -0,0 +1,46 @@
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use alwayson_codec::BytesStream;
+use bytes::BytesMut;
+
+use super::error::ThingWorxCodecError;
+use super::message::PyTwxMessage;
+
+/// Serializes messages into a single send buffer.
+#[pyclass(name = "MessageEncoder")]
+#[derive(Debug, Default)]
+pub struct PyMessageEncoder {
+    buffer: BytesMut,
+}
+
+#[pymethods]
+impl PyMessageEncoder {
+    #[new]
+    fn new() -> Self {
+        PyMessageEncoder::default()
+    }
+
+    /// Appends the encoded message to the buffer. On failure the buffer is left as it was.
+    fn write(&mut self, msg: &PyTwxMessage) -> PyResult<()> {
+        let start = self.buffer.len();
+        if let Err(e) = msg.inner.to_bytes(&mut self.buffer) {
+            self.buffer.truncate(start);
+            return Err(ThingWorxCodecError::new_err(format!(
+                "Message serialization error: {}",
+                e
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns everything written since the last flush and empties the buffer.
+    fn flush<'py>(&mut self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let data = self.buffer.split();
+        PyBytes::new_bound(py, &data)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MessageEncoder(buffered={})", self.buffer.len())
+    }
+}
@@ -1,11 +1,16 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::types::{PyBytes, PyDict};
+use serde_json::json;
 
 use alwayson_codec::{
-    event::TwxEvent as RustTwxEvent, property::TwxProperty as RustTwxProperty,
-    service::TwxService as RustTwxService,
+    datashape::DataShape as RustDataShape, event::TwxEvent as RustTwxEvent,
+    property::TwxProperty as RustTwxProperty, service::TwxService as RustTwxService,
 };
+use indexmap::IndexMap;
+
+use super::base_type::parse_base_type;
+use super::datashape::PyDataShape;
 
 #[pyclass(name = "TwxEvent")]
 #[derive(Clone, Debug)]
@@ -15,6 +20,21 @@ pub struct PyTwxEvent {
 
 #[pymethods]
 impl PyTwxEvent {
+    #[new]
+    fn new(name: String, description: String) -> PyResult<Self> {
+        let event_data = empty_datashape_json(&name)?;
+        let inner = entity_from_fields(
+            "Event",
+            json!({
+                "name": name,
+                "description": description,
+                "aspects": {},
+                "EventData": event_data,
+            }),
+        )?;
+        Ok(PyTwxEvent { inner })
+    }
+
     #[staticmethod]
     fn from_json(json_str: &str) -> PyResult<Self> {
         match serde_json::from_str::<RustTwxEvent>(json_str) {
@@ -48,11 +68,41 @@ impl PyTwxEvent {
         self.inner.description.clone()
     }
 
+    fn set_description(&mut self, description: String) {
+        self.inner.description = description;
+    }
+
+    /// Returns the DataShape describing the event payload, if the event defines one.
+    fn get_data_shape(&self) -> Option<PyDataShape> {
+        self.inner
+            .event_data
+            .clone()
+            .map(|datashape| PyDataShape { inner: datashape })
+    }
+
     fn to_json(&self) -> PyResult<String> {
         serde_json::to_string(&self.inner)
             .map_err(|e| PyValueError::new_err(format!("JSON serialization error: {e}")))
     }
 
+    fn __eq__(&self, other: &Self) -> PyResult<bool> {
+        // Compare every field, including the event's data shape and aspects
+        Ok(json_value(&self.inner)? == json_value(&other.inner)?)
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
+
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<(Bound<'py, PyAny>, (String,))> {
+        let from_json = py.get_type_bound::<Self>().getattr("from_json")?;
+        Ok((from_json, (self.to_json()?,)))
+    }
+
     fn __str__(&self) -> String {
         format!(
             "TwxEvent(name='{}', description='{}')",
@@ -76,6 +126,26 @@ pub struct PyTwxService {
 
 #[pymethods]
 impl PyTwxService {
+    #[new]
+    fn new(name: String, description: String) -> PyResult<Self> {
+        let inputs = empty_datashape_json("InputParameters")?;
+        // The result definition extends a data shape with the result's base type
+        let mut outputs = empty_datashape_json("result")?;
+        outputs["baseType"] = json!("NOTHING");
+        outputs["aspects"] = json!({});
+        let inner = entity_from_fields(
+            "Service",
+            json!({
+                "name": name,
+                "description": description,
+                "aspects": {},
+                "Inputs": inputs,
+                "Outputs": outputs,
+            }),
+        )?;
+        Ok(PyTwxService { inner })
+    }
+
     #[staticmethod]
     fn from_json(json_str: &str) -> PyResult<Self> {
         match serde_json::from_str::<RustTwxService>(json_str) {
@@ -111,11 +181,45 @@ impl PyTwxService {
         self.inner.description.clone()
     }
 
+    /// Returns the DataShape describing the service's input parameters, if defined.
+    fn get_input_datashape(&self) -> Option<PyDataShape> {
+        self.inner
+            .inputs
+            .clone()
+            .map(|datashape| PyDataShape { inner: datashape })
+    }
+
+    /// Returns the base type of the service result, e.g. `"INFOTABLE"` or `"NUMBER"`.
+    fn get_output_base_type(&self) -> String {
+        match &self.inner.outputs {
+            Some(outputs) => format!("{:?}", outputs.basetype),
+            None => "NOTHING".to_string(),
+        }
+    }
+
     fn to_json(&self) -> PyResult<String> {
         serde_json::to_string(&self.inner)
             .map_err(|e| PyValueError::new_err(format!("JSON serialization error: {e}")))
     }
 
+    fn __eq__(&self, other: &Self) -> PyResult<bool> {
+        // Compare every field, including the input and output definitions
+        Ok(json_value(&self.inner)? == json_value(&other.inner)?)
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
+
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<(Bound<'py, PyAny>, (String,))> {
+        let from_json = py.get_type_bound::<Self>().getattr("from_json")?;
+        Ok((from_json, (self.to_json()?,)))
+    }
+
     fn __str__(&self) -> String {
         format!(
             "TwxService(name='{}', description='{}')",
@@ -139,6 +243,24 @@ pub struct PyTwxProperty {
 
 #[pymethods]
 impl PyTwxProperty {
+    #[new]
+    fn new(name: String, base_type: &str, push_threshold: f64) -> PyResult<Self> {
+        let base_type = parse_base_type(base_type)?;
+        let push_threshold = check_push_threshold(push_threshold)?;
+        // Non-finite thresholds serialize as `null`, so set the threshold afterwards
+        let mut inner: RustTwxProperty = entity_from_fields(
+            "Property",
+            json!({
+                "name": name,
+                "baseType": format!("{base_type:?}"),
+                "pushThreshold": 0.0,
+                "aspects": {},
+            }),
+        )?;
+        inner.push_threshold = push_threshold;
+        Ok(PyTwxProperty { inner })
+    }
+
     #[staticmethod]
     fn from_json(json_str: &str) -> PyResult<Self> {
         match serde_json::from_str::<RustTwxProperty>(json_str) {
@@ -170,14 +292,38 @@ impl PyTwxProperty {
         self.inner.name.clone()
     }
 
+    /// Returns the description, or an empty string when the definition omits it.
+    fn get_description(&self) -> String {
+        self.inner.description.clone().unwrap_or_default()
+    }
+
     fn get_base_type(&self) -> String {
         format!("{:?}", self.inner.basetype)
     }
 
+    fn set_base_type(&mut self, base_type: &str) -> PyResult<()> {
+        self.inner.basetype = parse_base_type(base_type)?;
+        Ok(())
+    }
+
     fn get_push_threshold(&self) -> f64 {
         self.inner.push_threshold
     }
 
+    fn set_push_threshold(&mut self, threshold: f64) -> PyResult<()> {
+        self.inner.push_threshold = check_push_threshold(threshold)?;
+        Ok(())
+    }
+
+    /// Returns the property aspects (e.g. `isLogged`, `pushType`) as string values.
+    fn get_aspects<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        for (key, value) in &self.inner.aspects {
+            dict.set_item(key, aspect_text(value))?;
+        }
+        Ok(dict)
+    }
+
     fn should_read_edge_value(&self) -> bool {
         self.inner.should_read_edge_value()
     }
@@ -187,6 +333,41 @@ impl PyTwxProperty {
             .map_err(|e| PyValueError::new_err(format!("JSON serialization error: {e}")))
     }
 
+    fn __eq__(&self, other: &Self) -> bool {
+        // Bitwise threshold comparison keeps NaN thresholds equal to themselves
+        self.inner.name == other.inner.name
+            && self.inner.basetype == other.inner.basetype
+            && self.inner.push_threshold.to_bits() == other.inner.push_threshold.to_bits()
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
+
+    /// Pickle restore hook: JSON cannot carry NaN or infinite thresholds, so the exact
+    /// bit pattern travels alongside it.
+    #[staticmethod]
+    fn _from_pickle(json_str: &str, push_threshold_bits: u64) -> PyResult<Self> {
+        let mut property = Self::from_json(json_str)?;
+        property.inner.push_threshold = f64::from_bits(push_threshold_bits);
+        Ok(property)
+    }
+
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<(Bound<'py, PyAny>, (String, u64))> {
+        let from_pickle = py.get_type_bound::<Self>().getattr("_from_pickle")?;
+        // Non-finite thresholds serialize as `null`, so substitute a placeholder
+        let mut property = json_value(&self.inner)?;
+        property["pushThreshold"] = json!(0.0);
+        Ok((
+            from_pickle,
+            (property.to_string(), self.inner.push_threshold.to_bits()),
+        ))
+    }
+
     fn __str__(&self) -> String {
         format!(
             "TwxProperty(name='{}', base_type='{:?}', threshold={})",
@@ -201,3 +382,58 @@ impl PyTwxProperty {
         )
     }
 }
+
+/// Builds an upstream entity from the fields a constructor sets. Going through serde gives
+/// every other field the same default as `from_json`.
+fn entity_from_fields<T: serde::de::DeserializeOwned>(
+    kind: &str,
+    fields: serde_json::Value,
+) -> PyResult<T> {
+    serde_json::from_value(fields)
+        .map_err(|e| PyValueError::new_err(format!("{kind} construction error: {e}")))
+}
+
+/// Rejects negative and NaN push thresholds; infinity is allowed.
+fn check_push_threshold(threshold: f64) -> PyResult<f64> {
+    if threshold.is_nan() || threshold < 0.0 {
+        return Err(PyValueError::new_err(format!(
+            "Push threshold must be a non-negative number: {threshold}"
+        )));
+    }
+    Ok(threshold)
+}
+
+/// JSON form of a DataShape with no fields, for entity fields that hold one.
+fn empty_datashape_json(name: &str) -> PyResult<serde_json::Value> {
+    json_value(&RustDataShape {
+        name: Some(name.to_string()),
+        entries: IndexMap::new(),
+    })
+}
+
+fn json_value<T: serde::Serialize>(value: &T) -> PyResult<serde_json::Value> {
+    serde_json::to_value(value)
+        .map_err(|e| PyValueError::new_err(format!("JSON serialization error: {e}")))
+}
+
+/// Renders an aspect value as text: strings as-is, anything else as JSON (`true`, `0`).
+pub(crate) fn aspect_text(value: &serde_json::Value) -> String {
+    match value.as_str() {
+        Some(text) => text.to_string(),
+        None => value.to_string(),
+    }
+}
+
+/// Inverse of `aspect_text`: text that `aspect_text` would produce for a JSON scalar
+/// (`true`, `0`, `null`) becomes that scalar, anything else a string.
+pub(crate) fn aspect_value(text: &str) -> serde_json::Value {
+    match serde_json::from_str::<serde_json::Value>(text) {
+        Ok(value)
+            if !(value.is_string() || value.is_array() || value.is_object())
+                && value.to_string() == text =>
+        {
+            value
+        }
+        _ => serde_json::Value::String(text.to_string()),
+    }
+}
@@ -1,16 +1,70 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
+/// Error codes for binary/JSON serialization failures.
+pub(crate) const CODEC_ERROR_CODES: Range<i32> = 1000..2000;
+/// Error codes for protocol-level failures such as bad framing or rejected auth.
+pub(crate) const PROTOCOL_ERROR_CODES: Range<i32> = 2000..3000;
+/// Error codes for InfoTable schema violations.
+pub(crate) const SCHEMA_ERROR_CODES: Range<i32> = 3000..4000;
+
+// Raised when binary encoding or decoding fails. Subclasses `ValueError` so existing
+// `except ValueError` handlers keep working.
+create_exception!(alwayson, ThingWorxCodecError, PyValueError);
+
+// Raised for protocol-level failures: malformed framing, unknown wire codes or a
+// message type that cannot be used for the requested operation.
+create_exception!(alwayson, ThingWorxProtocolError, PyValueError);
+
+// Raised when InfoTable data does not fit its data shape: unknown or missing fields,
+// values of the wrong type and duplicate field definitions.
+create_exception!(alwayson, ThingWorxSchemaError, PyValueError);
+
 #[pyclass(name = "AlwaysOnError")]
 #[derive(Debug)]
 pub struct PyAlwaysOnError {
     pub(crate) message: String,
+    pub(crate) code: i32,
 }
 
 #[pymethods]
 impl PyAlwaysOnError {
     #[new]
-    fn new(message: String) -> Self {
-        PyAlwaysOnError { message }
+    #[pyo3(signature = (message, code=0))]
+    fn new(message: String, code: i32) -> Self {
+        PyAlwaysOnError { message, code }
+    }
+
+    fn get_code(&self) -> i32 {
+        self.code
+    }
+
+    /// Classifies the error as `"codec"`, `"protocol"`, `"schema"` or `"unknown"` by code.
+    fn category(&self) -> &'static str {
+        if CODEC_ERROR_CODES.contains(&self.code) {
+            "codec"
+        } else if PROTOCOL_ERROR_CODES.contains(&self.code) {
+            "protocol"
+        } else if SCHEMA_ERROR_CODES.contains(&self.code) {
+            "schema"
+        } else {
+            "unknown"
+        }
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.code == other.code && self.message == other.message
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (self.code, &self.message).hash(&mut hasher);
+        hasher.finish()
     }
 
     fn __str__(&self) -> String {
@@ -18,6 +72,6 @@ impl PyAlwaysOnError {
     }
 
     fn __repr__(&self) -> String {
-        format!("AlwaysOnError('{}')", self.message)
+        format!("AlwaysOnError('{}', code={})", self.message, self.code)
     }
 }
@@ -1,6 +1,8 @@
-use pyo3::exceptions::PyValueError;
+use std::collections::HashMap;
+
+use pyo3::exceptions::{PyIndexError, PyKeyError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::types::{timezone_utc_bound, PyByteArray, PyBytes, PyDateTime, PyDict, PyList};
 
 use alwayson_codec::{
     base::BaseType as RustBaseType,
@@ -9,7 +11,20 @@ use alwayson_codec::{
     primitive::TwPrim as RustTwPrim,
     BytesStream, SimpleJson,
 };
-use bytes::BytesMut;
+use bytes::{BufMut, Bytes, BytesMut};
+use indexmap::IndexMap;
+
+use super::base_type::parse_field_type;
+use super::datashape::PyDataShape;
+use super::error::{ThingWorxCodecError, ThingWorxSchemaError};
+use super::location::PyLocation;
+use super::primitive::{prim_byte_size, prim_to_py, to_hex};
+
+/// Each row starts with a 2-byte field count.
+const ROW_HEADER_SIZE: usize = 2;
+
+/// BLOB values longer than this are truncated in `dump()` output.
+const DUMP_BLOB_PREVIEW: usize = 32;
 
 #[pyclass(name = "InfoTable")]
 #[derive(Clone, Debug)]
@@ -22,8 +37,6 @@ impl PyInfoTable {
     #[new]
     #[pyo3(signature = (name=None))]
     fn new(name: Option<String>) -> PyResult<Self> {
-        use indexmap::IndexMap;
-
         let datashape = RustDataShape {
             name,
             entries: IndexMap::new(),
@@ -38,10 +51,13 @@ impl PyInfoTable {
     }
 
     #[staticmethod]
-    fn from_bytes(data: &[u8]) -> PyResult<Self> {
-        match RustInfoTable::from_bytes(data) {
+    fn from_bytes(py: Python, data: &[u8]) -> PyResult<Self> {
+        // Decoding only touches `data`, so other threads can run until the wrapper is built
+        let decoded =
+            py.allow_threads(|| RustInfoTable::from_bytes(data).map_err(|e| e.to_string()));
+        match decoded {
             Ok((infotable, _consumed)) => Ok(PyInfoTable { inner: infotable }),
-            Err(e) => Err(PyValueError::new_err(format!(
+            Err(e) => Err(ThingWorxCodecError::new_err(format!(
                 "InfoTable deserialization error: {}",
                 e
             ))),
@@ -49,14 +65,42 @@ impl PyInfoTable {
     }
 
     fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
-        let mut content = BytesMut::new();
-        match self.inner.to_bytes(&mut content) {
-            Ok(_) => Ok(PyBytes::new_bound(py, &content)),
-            Err(e) => Err(PyValueError::new_err(format!(
-                "InfoTable serialization error: {}",
-                e
-            ))),
+        Ok(PyBytes::new_bound(py, &self.encode(py)?))
+    }
+
+    /// Writes the `to_bytes()` encoding into `buffer` at `offset` and returns the number of
+    /// bytes written, raising `ValueError` if it does not fit; `byte_size()` gives the size
+    /// needed. The codec only writes into its own buffer, so this still encodes there
+    /// first; it saves creating a `bytes` object and Python's copy out of it.
+    #[pyo3(signature = (buffer, offset=0))]
+    fn to_bytes_into(
+        &self,
+        py: Python,
+        buffer: &Bound<'_, PyByteArray>,
+        offset: usize,
+    ) -> PyResult<usize> {
+        let content = self.encode(py)?;
+        let end = offset
+            .checked_add(content.len())
+            .filter(|end| *end <= buffer.len())
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Buffer too small: {} bytes needed at offset {offset}, buffer holds {}",
+                    content.len(),
+                    buffer.len()
+                ))
+            })?;
+        // SAFETY: no Python code runs between the length check and the copy, so nothing
+        // else can observe or resize the bytearray while it is borrowed
+        unsafe {
+            buffer.as_bytes_mut()[offset..end].copy_from_slice(&content);
         }
+        Ok(content.len())
+    }
+
+    /// Computes the length of `to_bytes()` output without serializing the rows.
+    fn byte_size(&self) -> PyResult<usize> {
+        infotable_byte_size(&self.inner)
     }
 
     fn get_row_count(&self) -> usize {
@@ -67,10 +111,21 @@ impl PyInfoTable {
         self.inner.datashape.entries.len()
     }
 
+    fn has_field(&self, field_name: &str) -> bool {
+        self.inner.datashape.entries.contains_key(field_name)
+    }
+
     fn get_datashape_name(&self) -> Option<String> {
         self.inner.datashape.name.clone()
     }
 
+    /// Returns a copy of the table's data shape.
+    fn get_datashape(&self) -> PyDataShape {
+        PyDataShape {
+            inner: self.inner.datashape.clone(),
+        }
+    }
+
     fn to_json(&self) -> PyResult<String> {
         // Use upstream Serde serialization directly
         serde_json::to_string(&self.inner)
@@ -88,29 +143,44 @@ impl PyInfoTable {
         }
     }
 
+    /// Returns a column-oriented `{field_name: [values...]}` dict, ready for `pd.DataFrame`.
+    /// Raises `ThingWorxSchemaError` if a row was added before one of the fields was defined.
+    fn to_pandas_records<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let mut columns: Vec<Vec<PyObject>> = self
+            .inner
+            .datashape
+            .entries
+            .keys()
+            .map(|_| Vec::with_capacity(self.inner.rows.len()))
+            .collect();
+
+        for (row_index, row) in self.inner.rows.iter().enumerate() {
+            self.check_row_complete(row_index, row)?;
+            for (column, value) in columns.iter_mut().zip(&row.fields) {
+                column.push(record_value_to_py(py, value)?);
+            }
+        }
+
+        let records = PyDict::new_bound(py);
+        for (field_name, column) in self.inner.datashape.entries.keys().zip(columns) {
+            records.set_item(field_name, PyList::new_bound(py, column))?;
+        }
+        Ok(records)
+    }
+
     fn add_field_definition(
         &mut self,
         name: String,
         base_type: String,
         description: String,
     ) -> PyResult<()> {
-        let rust_base_type = match base_type.to_uppercase().as_str() {
-            "BOOLEAN" => RustBaseType::BOOLEAN,
-            "INTEGER" => RustBaseType::INTEGER,
-            "LONG" => RustBaseType::LONG,
-            "NUMBER" => RustBaseType::NUMBER,
-            "STRING" => RustBaseType::STRING,
-            "DATETIME" => RustBaseType::DATETIME,
-            "BLOB" => RustBaseType::BLOB,
-            "LOCATION" => RustBaseType::LOCATION,
-            "INFOTABLE" => RustBaseType::INFOTABLE,
-            "VARIANT" => RustBaseType::VARIANT,
-            _ => {
-                return Err(PyValueError::new_err(format!(
-                    "Invalid base type: {base_type}"
-                )))
-            }
-        };
+        if self.inner.datashape.entries.contains_key(&name) {
+            return Err(ThingWorxSchemaError::new_err(format!(
+                "Field already defined: {name}"
+            )));
+        }
+
+        let rust_base_type = parse_field_type(&base_type)?;
 
         let entry = DataShapeEntry {
             name: name.clone(),
@@ -124,45 +194,349 @@ impl PyInfoTable {
     }
 
     fn add_row(&mut self, py: Python, row_dict: PyObject) -> PyResult<()> {
-        use pyo3::types::PyDict;
+        let row = self.row_from_dict(row_dict.downcast_bound::<PyDict>(py)?)?;
+        self.inner.rows.push(row);
+        Ok(())
+    }
+
+    /// Checks a row against the data shape without adding it, raising
+    /// `ThingWorxSchemaError` on the first violation.
+    fn validate_row(&self, py: Python, row_dict: PyObject) -> PyResult<()> {
+        self.row_from_dict(row_dict.downcast_bound::<PyDict>(py)?)?;
+        Ok(())
+    }
+
+    /// Returns a new InfoTable holding only the rows for which `predicate(row_dict)` is truthy.
+    fn filter_rows(&self, py: Python, predicate: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut rows = Vec::new();
+        for (row_index, row) in self.inner.rows.iter().enumerate() {
+            let row_dict = self.row_to_dict(py, row_index, row)?;
+            if predicate.call1((row_dict,))?.is_truthy()? {
+                rows.push(row.clone());
+            }
+        }
+
+        Ok(PyInfoTable {
+            inner: RustInfoTable {
+                datashape: self.inner.datashape.clone(),
+                rows,
+            },
+        })
+    }
+
+    /// Returns a new InfoTable with only the named fields, in the requested order.
+    fn project(&self, field_names: Vec<String>) -> PyResult<Self> {
+        let mut columns = Vec::with_capacity(field_names.len());
+        let mut entries = IndexMap::with_capacity(field_names.len());
+        for field_name in field_names {
+            if entries.contains_key(&field_name) {
+                return Err(PyValueError::new_err(format!(
+                    "Field listed more than once: {field_name}"
+                )));
+            }
+            let (index, _, entry) = self
+                .inner
+                .datashape
+                .entries
+                .get_full(&field_name)
+                .ok_or_else(|| PyKeyError::new_err(field_name.clone()))?;
+            columns.push((index, field_name.clone()));
+            entries.insert(field_name, entry.clone());
+        }
+
+        let mut rows = Vec::with_capacity(self.inner.rows.len());
+        for (row_index, row) in self.inner.rows.iter().enumerate() {
+            let mut fields = Vec::with_capacity(columns.len());
+            for (index, field_name) in &columns {
+                // Fields defined after a row was added have no value in that row
+                let value = row.fields.get(*index).ok_or_else(|| {
+                    ThingWorxSchemaError::new_err(format!(
+                        "Row {row_index} has no value for field {field_name}"
+                    ))
+                })?;
+                fields.push(value.clone());
+            }
+            rows.push(InfoTableRow { fields });
+        }
+
+        Ok(PyInfoTable {
+            inner: RustInfoTable {
+                datashape: RustDataShape {
+                    name: self.inner.datashape.name.clone(),
+                    entries,
+                },
+                rows,
+            },
+        })
+    }
+
+    fn extend(slf: &Bound<'_, Self>, other: &Bound<'_, PyInfoTable>) -> PyResult<()> {
+        // `table.extend(table)` would otherwise hold a shared and a mutable borrow at once
+        let rows = if other.is(slf) {
+            slf.borrow().inner.rows.clone()
+        } else {
+            let other = other.try_borrow()?;
+            slf.borrow().check_schema_compatible(&other.inner)?;
+            other.inner.rows.clone()
+        };
+        slf.try_borrow_mut()?.inner.rows.extend(rows);
+        Ok(())
+    }
+
+    /// Removes rows whose field values equal an earlier row, returning how many were removed.
+    fn deduplicate_rows(&mut self) -> PyResult<usize> {
+        let rows = &self.inner.rows;
+        let before = rows.len();
+        // Bucket rows by a key that equal rows share, then compare within the bucket
+        let mut buckets: HashMap<Vec<u8>, Vec<usize>> = HashMap::with_capacity(before);
+        let mut keep = Vec::with_capacity(before);
+        let mut key = BytesMut::new();
+        for (index, row) in rows.iter().enumerate() {
+            key.clear();
+            for field in &row.fields {
+                put_equality_key(&mut key, field)?;
+            }
+            let bucket = buckets.entry(key.to_vec()).or_default();
+            let duplicate = bucket.iter().any(|&kept| rows[kept].fields == row.fields);
+            if !duplicate {
+                bucket.push(index);
+            }
+            keep.push(!duplicate);
+        }
+
+        let mut keep = keep.into_iter();
+        self.inner.rows.retain(|_| keep.next().unwrap_or(true));
+        Ok(before - self.inner.rows.len())
+    }
+
+    fn swap_rows(&mut self, i: isize, j: isize) -> PyResult<()> {
+        let i = self.row_index(i)?;
+        let j = self.row_index(j)?;
+        self.inner.rows.swap(i, j);
+        Ok(())
+    }
+
+    fn reverse_rows(&mut self) {
+        self.inner.rows.reverse();
+    }
+
+    /// Returns a multi-line debug listing of the schema and every row.
+    /// Rows added before a field was defined show that field as `<missing>`.
+    fn dump(&self) -> String {
+        let mut out = format!(
+            "InfoTable {:?} ({} fields, {} rows)\nDataShape:\n",
+            self.inner.datashape.name.as_deref().unwrap_or(""),
+            self.inner.datashape.entries.len(),
+            self.inner.rows.len()
+        );
+        for (field_name, entry) in &self.inner.datashape.entries {
+            out.push_str(&format!(
+                "  {field_name}: {:?} {:?}\n",
+                entry.entry_type, entry.description
+            ));
+        }
+
+        out.push_str("Rows:\n");
+        for (index, row) in self.inner.rows.iter().enumerate() {
+            let values: Vec<String> = self
+                .inner
+                .datashape
+                .entries
+                .keys()
+                .enumerate()
+                .map(
+                    |(field_index, field_name)| match row.fields.get(field_index) {
+                        Some(value) => format!("{field_name}={}", dump_value(value)),
+                        None => format!("{field_name}=<missing>"),
+                    },
+                )
+                .collect();
+            out.push_str(&format!("  [{index}] {}\n", values.join(", ")));
+        }
+        out
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "InfoTable(rows={}, fields={})",
+            self.inner.rows.len(),
+            self.inner.datashape.entries.len()
+        )
+    }
+
+    fn __contains__(&self, field_name: &str) -> bool {
+        self.has_field(field_name)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "InfoTable(rows={}, fields={}, name={:?})",
+            self.inner.rows.len(),
+            self.inner.datashape.entries.len(),
+            self.inner.datashape.name
+        )
+    }
+}
+
+impl PyInfoTable {
+    fn encode(&self, py: Python) -> PyResult<BytesMut> {
+        let inner = &self.inner;
+        // Large tables take a while to encode, so let other threads run meanwhile
+        let encoded = py.allow_threads(|| {
+            let mut content = BytesMut::new();
+            inner
+                .to_bytes(&mut content)
+                .map(|_| content)
+                .map_err(|e| e.to_string())
+        });
+        encoded.map_err(|e| {
+            ThingWorxCodecError::new_err(format!("InfoTable serialization error: {}", e))
+        })
+    }
+
+    /// Ensures `other` has the same fields, in the same order and with the same types.
+    fn check_schema_compatible(&self, other: &RustInfoTable) -> PyResult<()> {
+        let ours = &self.inner.datashape.entries;
+        let theirs = &other.datashape.entries;
+        if ours.len() != theirs.len() {
+            return Err(ThingWorxSchemaError::new_err(format!(
+                "Schema mismatch: expected {} fields, got {}",
+                ours.len(),
+                theirs.len()
+            )));
+        }
+
+        for ((name, entry), (other_name, other_entry)) in ours.iter().zip(theirs.iter()) {
+            if name != other_name || entry.entry_type != other_entry.entry_type {
+                return Err(ThingWorxSchemaError::new_err(format!(
+                    "Schema mismatch: field {name} ({:?}) does not match {other_name} ({:?})",
+                    entry.entry_type, other_entry.entry_type
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures `row` has a value for every field. Rows added before a field was defined
+    /// have none for it.
+    fn check_row_complete(&self, row_index: usize, row: &InfoTableRow) -> PyResult<()> {
+        match self.inner.datashape.entries.get_index(row.fields.len()) {
+            Some((field_name, _)) => Err(ThingWorxSchemaError::new_err(format!(
+                "Row {row_index} has no value for field {field_name}"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Resolves a Python-style (possibly negative) row index.
+    fn row_index(&self, index: isize) -> PyResult<usize> {
+        let len = self.inner.rows.len() as isize;
+        let resolved = if index < 0 { index + len } else { index };
+        if resolved < 0 || resolved >= len {
+            return Err(PyIndexError::new_err(format!(
+                "Row index {index} out of range for {len} rows"
+            )));
+        }
+        Ok(resolved as usize)
+    }
+
+    fn row_to_dict<'py>(
+        &self,
+        py: Python<'py>,
+        row_index: usize,
+        row: &InfoTableRow,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        self.check_row_complete(row_index, row)?;
+        let dict = PyDict::new_bound(py);
+        for (field_name, value) in self.inner.datashape.entries.keys().zip(&row.fields) {
+            dict.set_item(field_name, prim_to_py(py, value)?)?;
+        }
+        Ok(dict)
+    }
+
+    /// Converts a row dict to fields in data shape order.
+    fn row_from_dict(&self, dict: &Bound<'_, PyDict>) -> PyResult<InfoTableRow> {
+        for key in dict.keys() {
+            let key: String = key.extract()?;
+            if !self.inner.datashape.entries.contains_key(&key) {
+                return Err(ThingWorxSchemaError::new_err(format!(
+                    "Unknown field: {key}"
+                )));
+            }
+        }
 
-        let dict = row_dict.downcast_bound::<PyDict>(py)?;
         let mut row_fields = Vec::new();
 
         // Process fields in the order they appear in the data shape
         for (field_name, field_def) in &self.inner.datashape.entries {
-            let py_value = dict
-                .get_item(field_name)?
-                .ok_or_else(|| PyValueError::new_err(format!("Missing field: {field_name}")))?;
+            let py_value = dict.get_item(field_name)?.ok_or_else(|| {
+                ThingWorxSchemaError::new_err(format!("Missing field: {field_name}"))
+            })?;
+            let mismatch = |expected: &str| {
+                ThingWorxSchemaError::new_err(format!(
+                    "Field {field_name} expects {expected}, got {}",
+                    py_value.get_type()
+                ))
+            };
 
             // Convert Python value to TwPrim based on field type
             let tw_prim = match field_def.entry_type {
                 RustBaseType::STRING => {
-                    let s: String = py_value.extract()?;
+                    let s: String = py_value.extract().map_err(|_| mismatch("a str"))?;
                     RustTwPrim::STRING(RustBaseType::STRING, s)
                 }
                 RustBaseType::NUMBER => {
-                    let n: f64 = py_value.extract()?;
+                    let n: f64 = py_value.extract().map_err(|_| mismatch("a float"))?;
                     RustTwPrim::NUMBER(RustBaseType::NUMBER, n)
                 }
                 RustBaseType::INTEGER => {
-                    let i: i32 = py_value.extract()?;
+                    let i: i32 = py_value.extract().map_err(|_| mismatch("a 32-bit int"))?;
                     RustTwPrim::INTEGER(RustBaseType::INTEGER, i)
                 }
                 RustBaseType::LONG => {
-                    let l: i64 = py_value.extract()?;
+                    let l: i64 = py_value.extract().map_err(|_| mismatch("a 64-bit int"))?;
                     RustTwPrim::LONG(RustBaseType::LONG, l)
                 }
                 RustBaseType::DATETIME => {
-                    let dt: i64 = py_value.extract()?;
+                    let dt: i64 = py_value
+                        .extract()
+                        .map_err(|_| mismatch("epoch milliseconds"))?;
                     RustTwPrim::DATETIME(RustBaseType::DATETIME, dt)
                 }
                 RustBaseType::BOOLEAN => {
-                    let b: bool = py_value.extract()?;
+                    let b: bool = py_value.extract().map_err(|_| mismatch("a bool"))?;
                     RustTwPrim::BOOLEAN(RustBaseType::BOOLEAN, b)
                 }
+                RustBaseType::BLOB => {
+                    let data = if let Ok(b) = py_value.downcast::<PyBytes>() {
+                        b.as_bytes().to_vec()
+                    } else if let Ok(b) = py_value.downcast::<PyByteArray>() {
+                        b.to_vec()
+                    } else {
+                        return Err(mismatch("bytes or bytearray"));
+                    };
+                    RustTwPrim::BLOB(RustBaseType::BLOB, Bytes::from(data))
+                }
+                RustBaseType::LOCATION => {
+                    if let Ok(location) = py_value.downcast::<PyLocation>() {
+                        location.borrow().to_prim()
+                    } else {
+                        let (latitude, longitude, elevation): (f64, f64, f64) =
+                            py_value.extract().map_err(|_| {
+                                mismatch("a Location or (latitude, longitude, elevation) tuple")
+                            })?;
+                        PyLocation::new(latitude, longitude, elevation).to_prim()
+                    }
+                }
+                RustBaseType::INFOTABLE => {
+                    let infotable = py_value
+                        .downcast::<PyInfoTable>()
+                        .map_err(|_| mismatch("an InfoTable"))?;
+                    let nested = infotable.try_borrow()?.inner.clone();
+                    RustTwPrim::INFOTABLE(RustBaseType::INFOTABLE, Box::new(nested))
+                }
                 _ => {
-                    return Err(PyValueError::new_err(format!(
+                    return Err(ThingWorxSchemaError::new_err(format!(
                         "Unsupported field type for field {field_name}: {:?}",
                         field_def.entry_type
                     )));
@@ -172,26 +546,93 @@ impl PyInfoTable {
             row_fields.push(tw_prim);
         }
 
-        let row = InfoTableRow { fields: row_fields };
-
-        self.inner.rows.push(row);
-        Ok(())
+        Ok(InfoTableRow { fields: row_fields })
     }
+}
 
-    fn __str__(&self) -> String {
-        format!(
+fn dump_value(prim: &RustTwPrim) -> String {
+    match prim {
+        RustTwPrim::BOOLEAN(_, v) => v.to_string(),
+        RustTwPrim::INTEGER(_, v) => v.to_string(),
+        RustTwPrim::LONG(_, v) => v.to_string(),
+        RustTwPrim::NUMBER(_, v) => v.to_string(),
+        RustTwPrim::STRING(_, v) => format!("{v:?}"),
+        RustTwPrim::DATETIME(_, v) => v.to_string(),
+        RustTwPrim::BLOB(_, v) if v.len() > DUMP_BLOB_PREVIEW => {
+            format!("{}...", to_hex(&v[..DUMP_BLOB_PREVIEW]))
+        }
+        RustTwPrim::BLOB(_, v) => to_hex(v),
+        RustTwPrim::LOCATION(..) => match PyLocation::from_prim(prim) {
+            Ok(location) => format!(
+                "({}, {}, {})",
+                location.latitude, location.longitude, location.elevation
+            ),
+            Err(e) => e.to_string(),
+        },
+        RustTwPrim::INFOTABLE(_, infotable) => format!(
             "InfoTable(rows={}, fields={})",
-            self.inner.rows.len(),
-            self.inner.datashape.entries.len()
-        )
+            infotable.rows.len(),
+            infotable.datashape.entries.len()
+        ),
+        RustTwPrim::VARIANT(_, boxed_prim) => dump_value(boxed_prim),
+        RustTwPrim::NOTHING(_) => "NOTHING".to_string(),
+    }
+}
+
+/// Appends a key for `prim` that is the same for any two values equal under `PartialEq`.
+/// Unequal values may share a key (NaN, nested tables), so callers still compare with `==`.
+fn put_equality_key(key: &mut BytesMut, prim: &RustTwPrim) -> PyResult<()> {
+    // -0.0 == 0.0, but their bits differ
+    let canonical = |v: f64| if v == 0.0 { 0.0 } else { v };
+    match prim {
+        RustTwPrim::NUMBER(_, v) => key.put_f64(canonical(*v)),
+        RustTwPrim::LOCATION(..) => {
+            let location = PyLocation::from_prim(prim)?;
+            key.put_f64(canonical(location.latitude));
+            key.put_f64(canonical(location.longitude));
+            key.put_f64(canonical(location.elevation));
+        }
+        RustTwPrim::INFOTABLE(_, infotable) => key.put_u64(infotable.rows.len() as u64),
+        RustTwPrim::VARIANT(_, boxed_prim) => put_equality_key(key, boxed_prim)?,
+        _ => {
+            prim.to_bytes(key).map_err(|e| {
+                ThingWorxCodecError::new_err(format!("Binary serialization error: {}", e))
+            })?;
+        }
     }
+    Ok(())
+}
 
-    fn __repr__(&self) -> String {
-        format!(
-            "InfoTable(rows={}, fields={}, name={:?})",
-            self.inner.rows.len(),
-            self.inner.datashape.entries.len(),
-            self.inner.datashape.name
-        )
+/// Like `prim_to_py`, but DATETIME becomes a UTC `datetime.datetime` for data analysis.
+fn record_value_to_py(py: Python, prim: &RustTwPrim) -> PyResult<PyObject> {
+    match prim {
+        RustTwPrim::DATETIME(_, millis) => {
+            let utc = timezone_utc_bound(py);
+            let timestamp = *millis as f64 / 1000.0;
+            Ok(PyDateTime::from_timestamp_bound(py, timestamp, Some(&utc))?.to_object(py))
+        }
+        RustTwPrim::VARIANT(_, boxed_prim) => record_value_to_py(py, boxed_prim),
+        _ => prim_to_py(py, prim),
+    }
+}
+
+pub(crate) fn infotable_byte_size(infotable: &RustInfoTable) -> PyResult<usize> {
+    // The schema is small, so encode it (with the row count) for an exact size
+    let header = RustInfoTable {
+        datashape: infotable.datashape.clone(),
+        rows: Vec::new(),
+    };
+    let mut content = BytesMut::new();
+    header.to_bytes(&mut content).map_err(|e| {
+        ThingWorxCodecError::new_err(format!("InfoTable serialization error: {}", e))
+    })?;
+
+    let mut size = content.len();
+    for row in &infotable.rows {
+        size += ROW_HEADER_SIZE;
+        for field in &row.fields {
+            size += prim_byte_size(field)?;
+        }
     }
+    Ok(size)
 }
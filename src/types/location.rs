@@ -0,0 +1,121 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::exceptions::{PyKeyError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use alwayson_codec::{base::BaseType as RustBaseType, primitive::TwPrim as RustTwPrim};
+
+/// A geographic position, the value of a ThingWorx `LOCATION`. Locations are immutable
+/// so that their hash stays stable; build a new one to change a coordinate.
+#[pyclass(name = "Location", frozen)]
+#[derive(Clone, Debug)]
+pub struct PyLocation {
+    #[pyo3(get)]
+    pub(crate) latitude: f64,
+    #[pyo3(get)]
+    pub(crate) longitude: f64,
+    #[pyo3(get)]
+    pub(crate) elevation: f64,
+}
+
+#[pymethods]
+impl PyLocation {
+    #[new]
+    #[pyo3(signature = (latitude, longitude, elevation=0.0))]
+    pub(crate) fn new(latitude: f64, longitude: f64, elevation: f64) -> Self {
+        PyLocation {
+            latitude,
+            longitude,
+            elevation,
+        }
+    }
+
+    /// Builds a location from a dict with `latitude` and `longitude` keys and an
+    /// optional `elevation` (default 0.0).
+    #[staticmethod]
+    fn from_dict(d: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let coordinate = |key: &str| -> PyResult<f64> {
+            d.get_item(key)?
+                .ok_or_else(|| PyKeyError::new_err(key.to_string()))?
+                .extract()
+        };
+        let elevation = match d.get_item("elevation")? {
+            Some(value) => value.extract()?,
+            None => 0.0,
+        };
+        Ok(PyLocation::new(
+            coordinate("latitude")?,
+            coordinate("longitude")?,
+            elevation,
+        ))
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("latitude", self.latitude)?;
+        dict.set_item("longitude", self.longitude)?;
+        dict.set_item("elevation", self.elevation)?;
+        Ok(dict)
+    }
+
+    /// Compares coordinate bit patterns, so a NaN coordinate equals itself.
+    fn __eq__(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.bits().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "Location(latitude={}, longitude={}, elevation={})",
+            self.latitude, self.longitude, self.elevation
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
+
+impl PyLocation {
+    fn bits(&self) -> (u64, u64, u64) {
+        (
+            self.latitude.to_bits(),
+            self.longitude.to_bits(),
+            self.elevation.to_bits(),
+        )
+    }
+
+    /// Builds the upstream LOCATION variant. This and `from_prim` are the only code that
+    /// depends on its layout.
+    pub(crate) fn to_prim(&self) -> RustTwPrim {
+        // The codec stores locations in wire order: longitude, latitude, elevation
+        RustTwPrim::LOCATION(
+            RustBaseType::LOCATION,
+            self.longitude,
+            self.latitude,
+            self.elevation,
+        )
+    }
+
+    pub(crate) fn from_prim(prim: &RustTwPrim) -> PyResult<Self> {
+        match prim {
+            RustTwPrim::LOCATION(_, longitude, latitude, elevation) => {
+                Ok(PyLocation::new(*latitude, *longitude, *elevation))
+            }
+            other => Err(PyTypeError::new_err(format!(
+                "TwPrim is not a LOCATION: {:?}",
+                other.base_type()
+            ))),
+        }
+    }
+}
+
+/// Encoded size of a LOCATION value: three 8-byte doubles.
+pub(crate) const LOCATION_BYTE_SIZE: usize = 24;
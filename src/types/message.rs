@@ -1,9 +1,27 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::types::{PyBytes, PyDict};
 
-use alwayson_codec::{message::tw_message::TwxMsg as RustTwxMsg, BytesStream};
+use alwayson_codec::{
+    base::{characteristic::Characteristic, entity_type::EntityType, BaseType as RustBaseType},
+    message::{
+        tw_bind::TwxBindBody, tw_header::TwxMsgHeader, tw_message::TwxMsg as RustTwxMsg,
+        tw_request::TwxReqBody, tw_response::TwxResBody,
+    },
+    primitive::TwPrim as RustTwPrim,
+    BytesStream,
+};
 use bytes::BytesMut;
+use serde_json::json;
+
+use super::base_type::parse_base_type;
+use super::error::{ThingWorxCodecError, ThingWorxProtocolError};
+use super::infotable::PyInfoTable;
+use super::primitive::{prim_byte_size, string_byte_size, to_hex, PyTwPrim};
+use super::wire::{
+    self, BIND_CODE, FIRST_RESPONSE_CODE, HEADER_SIZE, KEEP_ALIVE_SERVICE, METHODS,
+    PROTOCOL_VERSION,
+};
 
 #[pyclass(name = "TwxMessage")]
 #[derive(Clone, Debug)]
@@ -14,10 +32,10 @@ pub struct PyTwxMessage {
 #[pymethods]
 impl PyTwxMessage {
     #[staticmethod]
-    fn from_bytes(data: &[u8]) -> PyResult<Self> {
+    pub(crate) fn from_bytes(data: &[u8]) -> PyResult<Self> {
         match RustTwxMsg::from_bytes(data) {
             Ok((msg, _consumed)) => Ok(PyTwxMessage { inner: msg }),
-            Err(e) => Err(PyValueError::new_err(format!(
+            Err(e) => Err(ThingWorxCodecError::new_err(format!(
                 "Message deserialization error: {}",
                 e
             ))),
@@ -25,14 +43,62 @@ impl PyTwxMessage {
     }
 
     fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
-        let mut content = BytesMut::new();
-        match self.inner.to_bytes(&mut content) {
-            Ok(_) => Ok(PyBytes::new_bound(py, &content)),
-            Err(e) => Err(PyValueError::new_err(format!(
-                "Message serialization error: {}",
-                e
-            ))),
-        }
+        let content = self.encode()?;
+        Ok(PyBytes::new_bound(py, &content))
+    }
+
+    /// Returns the length of `to_bytes()` output, computed from the message fields.
+    fn byte_size(&self) -> PyResult<usize> {
+        let body_size = match &self.inner {
+            RustTwxMsg::Request(_, body) => {
+                // Entity type, characteristic and header count are one byte each
+                3 + string_byte_size(&body.entity_name)
+                    + string_byte_size(&body.target)
+                    + body
+                        .headers
+                        .iter()
+                        .map(|(key, value)| string_byte_size(key) + string_byte_size(value))
+                        .sum::<usize>()
+                    + prim_byte_size(&body.params)?
+            }
+            RustTwxMsg::Response(_, body) => prim_byte_size(&body.result)?,
+            RustTwxMsg::Bind(_, body) => {
+                // The gateway name is written empty when absent, then a 2-byte thing count
+                string_byte_size(body.gateway_name.as_deref().unwrap_or(""))
+                    + 2
+                    + body
+                        .things
+                        .iter()
+                        .map(|thing| string_byte_size(thing))
+                        .sum::<usize>()
+            }
+            // Auth messages are only a few claims long; their layout stays with the codec
+            RustTwxMsg::Auth(_, _) => return Ok(self.encode()?.len()),
+        };
+        Ok(HEADER_SIZE + body_size)
+    }
+
+    /// Returns the encoded `TwPrim` payload: the parameters of a request or the result of a
+    /// response, in the same form the builders take as `body`. Decode it with
+    /// `TwPrim.from_bytes`; `parse_body_as_infotable` returns an INFOTABLE payload directly.
+    /// Raises `ThingWorxProtocolError` for auth and bind messages, which carry no payload.
+    fn get_body_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let content = payload_bytes(self.require_payload()?)?;
+        Ok(PyBytes::new_bound(py, &content))
+    }
+
+    /// Returns the fixed header fields: `version`, `code`, `request_id`, `endpoint_id`,
+    /// `session_id` and `multipart`.
+    fn get_header_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let header = self.header();
+        let dict = PyDict::new_bound(py);
+        dict.set_item("version", header.version)?;
+        dict.set_item("code", header.code)?;
+        dict.set_item("request_id", header.request_id)?;
+        dict.set_item("endpoint_id", header.endpoint_id)?;
+        dict.set_item("session_id", header.session_id)?;
+        dict.set_item("multipart", header.multipart)?;
+        Ok(dict)
     }
 
     fn get_message_type(&self) -> String {
@@ -44,6 +110,54 @@ impl PyTwxMessage {
         }
     }
 
+    /// Returns every header field the message carries:
+    ///
+    /// - all messages: the `get_header_dict()` keys plus `message_type`
+    /// - requests: also `method`, `url`, `entity_type`, `entity_name`, `characteristic`,
+    ///   `target` and `headers` (a dict of the request headers)
+    fn get_all_headers<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = self.get_header_dict(py)?;
+        dict.set_item("message_type", self.get_message_type())?;
+
+        if let RustTwxMsg::Request(_, body) = &self.inner {
+            dict.set_item("method", self.get_method()?)?;
+            dict.set_item("url", request_url(body))?;
+            dict.set_item("entity_type", body.entity_type.to_string())?;
+            dict.set_item("entity_name", &body.entity_name)?;
+            dict.set_item("characteristic", body.characteristic.to_string())?;
+            dict.set_item("target", &body.target)?;
+
+            let headers = PyDict::new_bound(py);
+            for (key, value) in &body.headers {
+                headers.set_item(key, value)?;
+            }
+            dict.set_item("headers", headers)?;
+        }
+        Ok(dict)
+    }
+
+    /// Returns the request method (`GET`, `PUT`, `POST` or `DELETE`), or `None` for
+    /// messages that are not requests.
+    fn get_method(&self) -> PyResult<Option<String>> {
+        if !self.inner.is_request() {
+            return Ok(None);
+        }
+        let code = self.header().code;
+        Ok(METHODS
+            .iter()
+            .find(|(_, candidate)| *candidate == code)
+            .map(|(method, _)| method.to_string()))
+    }
+
+    /// Returns the resource URL of a request, e.g.
+    /// `/Thingworx/Things/MyThing/Properties/temperature`, or `None` for other messages.
+    fn get_url(&self) -> Option<String> {
+        match &self.inner {
+            RustTwxMsg::Request(_, body) => Some(request_url(body)),
+            _ => None,
+        }
+    }
+
     fn get_request_id(&self) -> u32 {
         self.inner.get_requestid()
     }
@@ -72,16 +186,231 @@ impl PyTwxMessage {
         self.inner.is_bind()
     }
 
+    /// Returns whether this is a keep-alive request. AlwaysOn has no keep-alive message code,
+    /// so these follow the ThingWorx URL convention instead: a `POST` to the `KeepAlive`
+    /// service of an entity, e.g. `/Thingworx/Things/MyThing/Services/KeepAlive`.
+    fn is_keep_alive(&self) -> PyResult<bool> {
+        let RustTwxMsg::Request(_, body) = &self.inner else {
+            return Ok(false);
+        };
+        Ok(self.get_method()?.as_deref() == Some("POST")
+            && body.characteristic.to_string() == "Services"
+            && body.target == KEEP_ALIVE_SERVICE)
+    }
+
+    /// Returns the message code from the header, e.g. `0x01` for a GET request or
+    /// `0x40` for a successful response.
+    fn message_code(&self) -> u8 {
+        self.header().code
+    }
+
     fn short_description(&self) -> String {
         self.inner.short_desc()
     }
 
     #[staticmethod]
-    fn build_auth(request_id: u32, app_key: String) -> PyResult<Self> {
+    pub(crate) fn build_auth(request_id: u32, app_key: String) -> PyResult<Self> {
         let msg = RustTwxMsg::build_auth_msg(request_id, &app_key);
         Ok(PyTwxMessage { inner: msg })
     }
 
+    #[staticmethod]
+    fn build_bind(request_id: u32, thing_name: String) -> Self {
+        let body = TwxBindBody {
+            gateway_name: None,
+            things: vec![thing_name],
+        };
+        PyTwxMessage {
+            inner: RustTwxMsg::Bind(new_header(BIND_CODE, request_id), body),
+        }
+    }
+
+    /// Builds a request addressed by a URL such as
+    /// `/Thingworx/Things/MyThing/Services/GetData`. `body` is an encoded `TwPrim`
+    /// (typically an INFOTABLE) carrying the request parameters.
+    #[staticmethod]
+    #[pyo3(signature = (request_id, method, uri, headers=None, body=None))]
+    fn build_request(
+        request_id: u32,
+        method: &str,
+        uri: &str,
+        headers: Option<&Bound<'_, PyDict>>,
+        body: Option<&[u8]>,
+    ) -> PyResult<Self> {
+        let code = wire::lookup_code(&METHODS, &method.to_uppercase(), "method")?;
+
+        let segments: Vec<&str> = uri
+            .trim_start_matches('/')
+            .trim_start_matches("Thingworx/")
+            .split('/')
+            .collect();
+        let [entity_type, entity_name, characteristic, target] = segments[..] else {
+            return Err(PyValueError::new_err(format!(
+                "Invalid request URL: {uri} (expected /<EntityType>/<name>/<Characteristic>/<target>)"
+            )));
+        };
+
+        let entity_type: EntityType = entity_type
+            .parse()
+            .map_err(|_| PyValueError::new_err(format!("Unknown entity type: {entity_type}")))?;
+        let characteristic: Characteristic = characteristic.parse().map_err(|_| {
+            PyValueError::new_err(format!("Unknown characteristic: {characteristic}"))
+        })?;
+
+        let headers = match headers {
+            Some(headers) => headers
+                .iter()
+                .map(|(key, value)| Ok((key.extract::<String>()?, value.extract::<String>()?)))
+                .collect::<PyResult<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        if headers.len() > u8::MAX as usize {
+            return Err(PyValueError::new_err(
+                "A request can carry at most 255 headers",
+            ));
+        }
+
+        let body = TwxReqBody {
+            entity_type,
+            entity_name: entity_name.to_string(),
+            characteristic,
+            target: target.to_string(),
+            headers,
+            params: decode_body(body)?,
+        };
+        Ok(PyTwxMessage {
+            inner: RustTwxMsg::Request(new_header(code, request_id), body),
+        })
+    }
+
+    /// Builds a response with the given status code (e.g. `0x40` for success). `body` is
+    /// the encoded result, typically an INFOTABLE `TwPrim`.
+    #[staticmethod]
+    #[pyo3(signature = (request_id, status_code, body=None))]
+    fn build_response(request_id: u32, status_code: u32, body: Option<&[u8]>) -> PyResult<Self> {
+        let code = u8::try_from(status_code)
+            .ok()
+            .filter(|code| *code >= FIRST_RESPONSE_CODE)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!("Invalid response status code: {status_code:#x}"))
+            })?;
+
+        let body = TwxResBody {
+            result: decode_body(body)?,
+        };
+        Ok(PyTwxMessage {
+            inner: RustTwxMsg::Response(new_header(code, request_id), body),
+        })
+    }
+
+    /// Returns the header fields and `body`, the payload as hex (as `get_body_bytes` returns
+    /// it), or `null` for auth and bind messages.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.json_summary()?)
+            .map_err(|e| PyValueError::new_err(format!("JSON serialization error: {e}")))
+    }
+
+    /// Like `to_json`, indented, and with the body decoded inline when it holds a `TwPrim`.
+    fn to_pretty_json(&self) -> PyResult<String> {
+        let mut summary = self.json_summary()?;
+        if let Some(prim) = self.payload() {
+            if let Ok(decoded) = prim.to_json_typed(prim.base_type()) {
+                summary["body"] = decoded;
+            }
+        }
+        serde_json::to_string_pretty(&summary)
+            .map_err(|e| PyValueError::new_err(format!("JSON serialization error: {e}")))
+    }
+
+    fn parse_body_as_infotable(&self) -> PyResult<PyInfoTable> {
+        match self.decode_payload()? {
+            RustTwPrim::INFOTABLE(_, infotable) => Ok(PyInfoTable { inner: *infotable }),
+            other => Err(PyValueError::new_err(format!(
+                "Message body is not an InfoTable: {:?}",
+                other.base_type()
+            ))),
+        }
+    }
+
+    fn parse_body_as_twprim(&self, base_type: &str) -> PyResult<PyTwPrim> {
+        let expected = parse_base_type(base_type)?;
+        let prim = self.decode_payload()?;
+        if prim.base_type() != expected {
+            return Err(PyValueError::new_err(format!(
+                "Message body is {:?}, expected {:?}",
+                prim.base_type(),
+                expected
+            )));
+        }
+        Ok(PyTwPrim { inner: prim })
+    }
+
+    /// Returns an annotated header breakdown followed by an `xxd`-style hex dump.
+    fn dump(&self) -> PyResult<String> {
+        let content = self.encode()?;
+        let header = self.header();
+        let body_len = content.len() - HEADER_SIZE;
+
+        let mut out = format!(
+            "TwxMessage {} ({} bytes)\n",
+            self.get_message_type(),
+            content.len()
+        );
+        let fields = [
+            (0x00, "version", format!("{:#04x}", header.version)),
+            (0x01, "code", format!("{:#04x}", header.code)),
+            (0x02, "request_id", header.request_id.to_string()),
+            (0x06, "endpoint_id", header.endpoint_id.to_string()),
+            (0x0a, "session_id", header.session_id.to_string()),
+            (0x0e, "multipart", header.multipart.to_string()),
+            (HEADER_SIZE, "body", format!("{body_len} bytes")),
+        ];
+        for (offset, name, value) in fields {
+            out.push_str(&format!("  {offset:#06x}  {name:<12}{value}\n"));
+        }
+
+        for (line, chunk) in content.chunks(16).enumerate() {
+            let hex: Vec<String> = chunk.iter().map(|byte| format!("{byte:02x}")).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            out.push_str(&format!(
+                "{:08x}: {:<47}  |{ascii}|\n",
+                line * 16,
+                hex.join(" ")
+            ));
+        }
+        Ok(out)
+    }
+
+    fn __eq__(&self, other: &Self) -> PyResult<bool> {
+        // Messages are equal when they encode to the same bytes
+        Ok(self.encode()? == other.encode()?)
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
+
+    fn __reduce__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyBytes>,))> {
+        let from_bytes = py.get_type_bound::<Self>().getattr("from_bytes")?;
+        Ok((from_bytes, (self.to_bytes(py)?,)))
+    }
+
     fn __str__(&self) -> String {
         self.short_description()
     }
@@ -90,3 +419,106 @@ impl PyTwxMessage {
         format!("TwxMessage({})", self.get_message_type())
     }
 }
+
+impl PyTwxMessage {
+    fn header(&self) -> &TwxMsgHeader {
+        match &self.inner {
+            RustTwxMsg::Request(header, _)
+            | RustTwxMsg::Response(header, _)
+            | RustTwxMsg::Auth(header, _)
+            | RustTwxMsg::Bind(header, _) => header,
+        }
+    }
+
+    /// Header fields plus the hex-encoded body.
+    fn json_summary(&self) -> PyResult<serde_json::Value> {
+        let body = match self.payload() {
+            Some(prim) => Some(to_hex(&payload_bytes(prim)?)),
+            None => None,
+        };
+        Ok(json!({
+            "message_type": self.get_message_type(),
+            "request_id": self.inner.get_requestid(),
+            "session_id": self.inner.get_sessionid(),
+            "endpoint": self.inner.get_endpoint(),
+            "body": body,
+        }))
+    }
+
+    /// The `TwPrim` payload: the parameters of a request or the result of a response.
+    /// Other messages carry no payload.
+    fn payload(&self) -> Option<&RustTwPrim> {
+        match &self.inner {
+            RustTwxMsg::Request(_, body) => Some(&body.params),
+            RustTwxMsg::Response(_, body) => Some(&body.result),
+            RustTwxMsg::Auth(_, _) | RustTwxMsg::Bind(_, _) => None,
+        }
+    }
+
+    fn require_payload(&self) -> PyResult<&RustTwPrim> {
+        self.payload().ok_or_else(|| {
+            ThingWorxProtocolError::new_err(format!(
+                "{} messages carry no payload",
+                self.get_message_type()
+            ))
+        })
+    }
+
+    fn decode_payload(&self) -> PyResult<RustTwPrim> {
+        self.require_payload().cloned()
+    }
+
+    fn encode(&self) -> PyResult<BytesMut> {
+        let mut content = BytesMut::new();
+        match self.inner.to_bytes(&mut content) {
+            Ok(_) => Ok(content),
+            Err(e) => Err(ThingWorxCodecError::new_err(format!(
+                "Message serialization error: {}",
+                e
+            ))),
+        }
+    }
+}
+
+/// Header for a message built locally. Endpoint id, session id and the multipart marker
+/// are assigned by the platform.
+fn new_header(code: u8, request_id: u32) -> TwxMsgHeader {
+    TwxMsgHeader {
+        version: PROTOCOL_VERSION,
+        code,
+        request_id,
+        endpoint_id: 0,
+        session_id: 0,
+        multipart: false,
+    }
+}
+
+/// Decodes an encoded `TwPrim` passed as a message body; an absent or empty body is NOTHING.
+fn decode_body(body: Option<&[u8]>) -> PyResult<RustTwPrim> {
+    match body {
+        Some(body) if !body.is_empty() => match RustTwPrim::from_bytes(body) {
+            Ok((prim, _consumed)) => Ok(prim),
+            Err(e) => Err(ThingWorxCodecError::new_err(format!(
+                "Message body deserialization error: {}",
+                e
+            ))),
+        },
+        _ => Ok(RustTwPrim::NOTHING(RustBaseType::NOTHING)),
+    }
+}
+
+/// Formats a request route as a URL such as `/Thingworx/Things/MyThing/Properties/temp`.
+fn request_url(body: &TwxReqBody) -> String {
+    format!(
+        "/Thingworx/{}/{}/{}/{}",
+        body.entity_type, body.entity_name, body.characteristic, body.target
+    )
+}
+
+fn payload_bytes(prim: &RustTwPrim) -> PyResult<BytesMut> {
+    let mut content = BytesMut::new();
+    prim.to_bytes(&mut content).map_err(|e| {
+        ThingWorxCodecError::new_err(format!("Message body serialization error: {}", e))
+    })?;
+    Ok(content)
+}
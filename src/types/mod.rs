@@ -1,13 +1,24 @@
 pub mod base_type;
+pub mod datashape;
+pub mod decoder;
+pub mod encoder;
 pub mod entities;
 pub mod error;
 pub mod infotable;
+pub mod location;
 pub mod message;
 pub mod primitive;
+pub(crate) mod wire;
 
 pub use base_type::PyBaseType;
+pub use datashape::{PyDataShape, PyDataShapeEntry};
+pub use decoder::PyMessageDecoder;
+pub use encoder::PyMessageEncoder;
 pub use entities::{PyTwxEvent, PyTwxProperty, PyTwxService};
-pub use error::PyAlwaysOnError;
+pub use error::{
+    PyAlwaysOnError, ThingWorxCodecError, ThingWorxProtocolError, ThingWorxSchemaError,
+};
 pub use infotable::PyInfoTable;
+pub use location::PyLocation;
 pub use message::PyTwxMessage;
 pub use primitive::PyTwPrim;
@@ -9,6 +9,10 @@ use alwayson_codec::{
 };
 use bytes::{Bytes, BytesMut};
 
+use super::error::ThingWorxCodecError;
+use super::infotable::{infotable_byte_size, PyInfoTable};
+use super::location::{PyLocation, LOCATION_BYTE_SIZE};
+
 #[pyclass(name = "TwPrim")]
 #[derive(Clone, Debug)]
 pub struct PyTwPrim {
@@ -66,6 +70,13 @@ impl PyTwPrim {
         })
     }
 
+    #[staticmethod]
+    fn location(value: &PyLocation) -> PyResult<Self> {
+        Ok(PyTwPrim {
+            inner: value.to_prim(),
+        })
+    }
+
     #[staticmethod]
     fn infotable_empty() -> PyResult<Self> {
         // Create an empty InfoTable with no fields and no rows
@@ -90,7 +101,7 @@ impl PyTwPrim {
         let mut content = BytesMut::new();
         match self.inner.to_bytes(&mut content) {
             Ok(_) => Ok(PyBytes::new_bound(py, &content)),
-            Err(e) => Err(PyValueError::new_err(format!(
+            Err(e) => Err(ThingWorxCodecError::new_err(format!(
                 "Binary serialization error: {}",
                 e
             ))),
@@ -101,7 +112,7 @@ impl PyTwPrim {
     fn from_bytes(data: &[u8]) -> PyResult<Self> {
         match RustTwPrim::from_bytes(data) {
             Ok((prim, _consumed)) => Ok(PyTwPrim { inner: prim }),
-            Err(e) => Err(PyValueError::new_err(format!(
+            Err(e) => Err(ThingWorxCodecError::new_err(format!(
                 "Binary deserialization error: {}",
                 e
             ))),
@@ -154,34 +165,7 @@ impl PyTwPrim {
     }
 
     fn get_value(&self, py: Python) -> PyResult<PyObject> {
-        match &self.inner {
-            RustTwPrim::BOOLEAN(_, v) => Ok(v.to_object(py)),
-            RustTwPrim::INTEGER(_, v) => Ok(v.to_object(py)),
-            RustTwPrim::LONG(_, v) => Ok(v.to_object(py)),
-            RustTwPrim::NUMBER(_, v) => Ok(v.to_object(py)),
-            RustTwPrim::STRING(_, v) => Ok(v.to_object(py)),
-            RustTwPrim::DATETIME(_, v) => Ok(v.to_object(py)),
-            RustTwPrim::BLOB(_, v) => Ok(PyBytes::new_bound(py, v.as_ref()).to_object(py)),
-            RustTwPrim::INFOTABLE(_, infotable) => {
-                // For now, return a simple string representation of the InfoTable
-                // TODO: Implement proper InfoTable Python wrapper
-                let info_str = format!(
-                    "InfoTable(rows={}, fields={})",
-                    infotable.rows.len(),
-                    infotable.datashape.entries.len()
-                );
-                Ok(info_str.to_object(py))
-            }
-            RustTwPrim::NOTHING(_) => Ok(py.None()),
-            RustTwPrim::VARIANT(_, boxed_prim) => {
-                // Recursively get the value from the wrapped primitive
-                let wrapped_prim = PyTwPrim {
-                    inner: (**boxed_prim).clone(),
-                };
-                wrapped_prim.get_value(py)
-            }
-            _ => Err(PyTypeError::new_err("Unsupported type for get_value")),
-        }
+        prim_to_py(py, &self.inner)
     }
 
     fn __str__(&self) -> String {
@@ -229,3 +213,59 @@ impl PyTwPrim {
         }
     }
 }
+
+/// Converts a primitive into the closest native Python value.
+pub(crate) fn prim_to_py(py: Python, prim: &RustTwPrim) -> PyResult<PyObject> {
+    match prim {
+        RustTwPrim::BOOLEAN(_, v) => Ok(v.to_object(py)),
+        RustTwPrim::INTEGER(_, v) => Ok(v.to_object(py)),
+        RustTwPrim::LONG(_, v) => Ok(v.to_object(py)),
+        RustTwPrim::NUMBER(_, v) => Ok(v.to_object(py)),
+        RustTwPrim::STRING(_, v) => Ok(v.to_object(py)),
+        RustTwPrim::DATETIME(_, v) => Ok(v.to_object(py)),
+        RustTwPrim::BLOB(_, v) => Ok(PyBytes::new_bound(py, v.as_ref()).to_object(py)),
+        RustTwPrim::LOCATION(..) => Ok(Py::new(py, PyLocation::from_prim(prim)?)?.to_object(py)),
+        RustTwPrim::INFOTABLE(_, infotable) => Ok(Py::new(
+            py,
+            PyInfoTable {
+                inner: (**infotable).clone(),
+            },
+        )?
+        .to_object(py)),
+        RustTwPrim::NOTHING(_) => Ok(py.None()),
+        // Recursively get the value from the wrapped primitive
+        RustTwPrim::VARIANT(_, boxed_prim) => prim_to_py(py, boxed_prim),
+    }
+}
+
+/// Number of bytes `to_bytes` writes for `prim`, including the leading type byte.
+pub(crate) fn prim_byte_size(prim: &RustTwPrim) -> PyResult<usize> {
+    let value_size = match prim {
+        RustTwPrim::BOOLEAN(_, _) => 1,
+        RustTwPrim::INTEGER(_, _) => 4,
+        RustTwPrim::LONG(_, _) | RustTwPrim::NUMBER(_, _) | RustTwPrim::DATETIME(_, _) => 8,
+        RustTwPrim::STRING(_, v) => string_byte_size(v),
+        // BLOBs carry a 4-byte length prefix
+        RustTwPrim::BLOB(_, v) => 4 + v.len(),
+        // Longitude, latitude and elevation as three doubles
+        RustTwPrim::LOCATION(..) => LOCATION_BYTE_SIZE,
+        RustTwPrim::INFOTABLE(_, infotable) => infotable_byte_size(infotable)?,
+        RustTwPrim::VARIANT(_, boxed_prim) => prim_byte_size(boxed_prim)?,
+        RustTwPrim::NOTHING(_) => 0,
+    };
+    Ok(1 + value_size)
+}
+
+/// Strings shorter than 128 bytes use a 1-byte length prefix, longer ones a 4-byte prefix.
+pub(crate) fn string_byte_size(value: &str) -> usize {
+    if value.len() < 128 {
+        1 + value.len()
+    } else {
+        4 + value.len()
+    }
+}
+
+/// Lower-case hex encoding of `data`.
+pub(crate) fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,38 @@
+//! Constants of the AlwaysOn wire format that the bindings need outside the codec.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Protocol version written into every message header.
+pub(crate) const PROTOCOL_VERSION: u8 = 0x01;
+
+/// Version, code, request id, endpoint id, session id and multipart marker.
+pub(crate) const HEADER_SIZE: usize = 15;
+
+/// Type byte used for an absent value (ThingWorx `NOTHING`, i.e. -1).
+pub(crate) const NOTHING_TYPE: u8 = 0xFF;
+
+/// Message code for binding things to the connection.
+pub(crate) const BIND_CODE: u8 = 0x0A;
+
+/// Service that keep-alive requests invoke; the wire format has no keep-alive message code.
+pub(crate) const KEEP_ALIVE_SERVICE: &str = "KeepAlive";
+
+/// Message codes at or above this value are responses (`0x40` is success).
+pub(crate) const FIRST_RESPONSE_CODE: u8 = 0x40;
+
+/// Request methods and their message codes.
+pub(crate) const METHODS: [(&str, u8); 4] = [
+    ("GET", 0x01),
+    ("PUT", 0x02),
+    ("POST", 0x03),
+    ("DELETE", 0x04),
+];
+
+pub(crate) fn lookup_code(table: &[(&str, u8)], name: &str, kind: &str) -> PyResult<u8> {
+    table
+        .iter()
+        .find(|(candidate, _)| *candidate == name)
+        .map(|(_, code)| *code)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown {kind}: {name}")))
+}
@@ -0,0 +1,59 @@
+from typing import Callable
+
+import pytest
+
+from alwayson import InfoTable, TwPrim, TwxMessage
+
+MESSAGE_FIXTURES = [
+    "request_message",
+    "response_message",
+    "auth_message",
+    "bind_message",
+]
+
+
+@pytest.fixture
+def request_message() -> TwxMessage:
+    return TwxMessage.build_request(
+        1,
+        "POST",
+        "/Thingworx/Things/EdgeThing/Services/GetData",
+        {"Accept": "application/octet-stream"},
+        TwPrim.string("hello").to_bytes(),
+    )
+
+
+@pytest.fixture
+def response_message() -> TwxMessage:
+    return TwxMessage.build_response(2, 0x40, TwPrim.number(1.5).to_bytes())
+
+
+@pytest.fixture
+def auth_message() -> TwxMessage:
+    return TwxMessage.build_auth(3, "app-key")
+
+
+@pytest.fixture
+def bind_message() -> TwxMessage:
+    return TwxMessage.build_bind(4, "EdgeThing")
+
+
+@pytest.fixture(params=MESSAGE_FIXTURES)
+def message(request: pytest.FixtureRequest) -> TwxMessage:
+    """Each message variant in turn."""
+    return request.getfixturevalue(request.param)
+
+
+def build_table() -> InfoTable:
+    table = InfoTable("Readings")
+    table.add_field_definition("sensor", "STRING", "Sensor name")
+    table.add_field_definition("value", "NUMBER", "Reading")
+    for sensor, value in [("a", 1.0), ("b", 2.0), ("a", 1.0), ("c", 3.0)]:
+        table.add_row({"sensor": sensor, "value": value})
+    return table
+
+
+@pytest.fixture
+def make_table() -> Callable[[], InfoTable]:
+    """Builds a new `Readings` table on each call, for tests that need several."""
+    return build_table
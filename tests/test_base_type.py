@@ -0,0 +1,90 @@
+import pytest
+
+from alwayson import BaseType, iter_base_types, supported_base_types
+
+
+def test_equality() -> None:
+    assert BaseType("NUMBER") == BaseType("number")
+    assert BaseType("NUMBER") != BaseType("INTEGER")
+
+
+def test_hash_matches_equality() -> None:
+    assert len({BaseType("STRING"), BaseType("string"), BaseType("LONG")}) == 2
+    assert {BaseType("BOOLEAN"): 1}[BaseType("BOOLEAN")] == 1
+
+
+def test_int_is_the_wire_code() -> None:
+    assert int(BaseType("STRING")) == 0
+    assert int(BaseType("INFOTABLE")) == 5
+    assert int(BaseType("LONG")) == 23
+    assert int(BaseType("NOTHING")) == 0xFF
+
+
+@pytest.mark.parametrize("name", ["STRING", "NUMBER", "LOCATION", "BLOB", "NOTHING"])
+def test_discriminant_round_trip(name: str) -> None:
+    base_type = BaseType(name)
+
+    assert BaseType.from_discriminant(int(base_type)) == base_type
+
+
+def test_unknown_discriminant_is_rejected() -> None:
+    with pytest.raises(ValueError, match="Unknown base type code"):
+        BaseType.from_discriminant(1000)
+
+
+def test_all_types_covers_every_code() -> None:
+    all_types = BaseType.all_types()
+
+    assert "NOTHING" in [str(base_type) for base_type in all_types]
+    for base_type in all_types:
+        assert BaseType.from_discriminant(int(base_type)) == base_type
+
+
+def test_is_numeric() -> None:
+    assert BaseType("INTEGER").is_numeric()
+    assert BaseType("NUMBER").is_numeric()
+    assert not BaseType("STRING").is_numeric()
+
+
+def test_is_scalar() -> None:
+    assert BaseType("LOCATION").is_scalar()
+    assert not BaseType("INFOTABLE").is_scalar()
+    assert not BaseType("NOTHING").is_scalar()
+
+
+@pytest.mark.parametrize(
+    ("name", "expected"),
+    [
+        ("BOOLEAN", False),
+        ("INTEGER", 0),
+        ("LONG", 0),
+        ("NUMBER", 0.0),
+        ("STRING", ""),
+        ("DATETIME", 0),
+    ],
+)
+def test_default_value(name: str, expected: object) -> None:
+    value = BaseType(name).default_value()
+
+    assert value.get_type() == name
+    assert value.get_value() == expected
+
+
+def test_is_compatible_with() -> None:
+    integer = BaseType("INTEGER")
+
+    assert integer.is_compatible_with(BaseType("LONG"))
+    assert integer.is_compatible_with(BaseType("NUMBER"))
+    assert BaseType("STRING").is_compatible_with(BaseType("VARIANT"))
+    assert not BaseType("LONG").is_compatible_with(BaseType("NUMBER"))
+    assert not BaseType("NUMBER").is_compatible_with(integer)
+
+
+def test_iter_base_types_matches_all_types() -> None:
+    assert list(iter_base_types()) == BaseType.all_types()
+
+
+def test_supported_base_types_lists_all_type_names() -> None:
+    names = [str(base_type) for base_type in BaseType.all_types()]
+
+    assert supported_base_types() == names
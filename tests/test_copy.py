@@ -0,0 +1,28 @@
+import copy
+from typing import Callable
+
+import pytest
+
+from alwayson import TwxService
+
+
+@pytest.fixture
+def service() -> TwxService:
+    return TwxService("GetData", "Returns the latest readings")
+
+
+# Classes without mutators: a copy only needs to be a distinct, equal object
+@pytest.mark.parametrize("fixture", ["response_message", "service"])
+@pytest.mark.parametrize("copier", [copy.copy, copy.deepcopy])
+def test_copy_is_an_equal_new_object(
+    fixture: str,
+    copier: Callable[[object], object],
+    request: pytest.FixtureRequest,
+) -> None:
+    original = request.getfixturevalue(fixture)
+
+    duplicate = copier(original)
+
+    assert duplicate is not original
+    assert duplicate == original
+    assert type(duplicate) is type(original)
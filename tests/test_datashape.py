@@ -0,0 +1,182 @@
+import json
+from typing import Callable
+
+import pytest
+
+from alwayson import DataShape, InfoTable, ThingWorxSchemaError
+
+
+def test_infotable_exposes_its_datashape(make_table: Callable[[], InfoTable]) -> None:
+    shape = make_table().get_datashape()
+
+    assert shape.get_name() == "Readings"
+    assert shape.get_entry_names() == ["sensor", "value"]
+    assert shape.get_entry_count() == 2
+
+
+def test_nothing_is_not_an_entry_type() -> None:
+    with pytest.raises(ThingWorxSchemaError):
+        DataShape().add_entry("empty", "NOTHING", "")
+
+
+def test_json_round_trip(make_table: Callable[[], InfoTable]) -> None:
+    shape = make_table().get_datashape()
+
+    restored = DataShape.from_json(shape.to_json())
+
+    assert restored.to_json() == shape.to_json()
+    assert restored.get_name() == "Readings"
+
+
+def make_shape() -> DataShape:
+    shape = DataShape("Readings")
+    shape.add_entry("sensor", "STRING", "Sensor name")
+    shape.add_entry("value", "NUMBER", "Reading")
+    return shape
+
+
+def test_add_entry_appends_fields(make_table: Callable[[], InfoTable]) -> None:
+    shape = make_shape()
+
+    assert shape.get_entry_names() == ["sensor", "value"]
+    assert shape.to_json() == make_table().get_datashape().to_json()
+
+
+def test_add_entry_rejects_duplicates() -> None:
+    with pytest.raises(ThingWorxSchemaError):
+        make_shape().add_entry("sensor", "STRING", "")
+
+
+def test_remove_entry() -> None:
+    shape = make_shape()
+
+    shape.remove_entry("sensor")
+
+    assert shape.get_entry_names() == ["value"]
+    with pytest.raises(KeyError):
+        shape.remove_entry("sensor")
+
+
+def test_merge_appends_new_fields() -> None:
+    other = DataShape()
+    other.add_entry("value", "NUMBER", "")
+    other.add_entry("unit", "STRING", "")
+
+    merged = make_shape().merge(other)
+
+    assert merged.get_entry_names() == ["sensor", "value", "unit"]
+
+
+def test_merge_rejects_conflicting_types() -> None:
+    other = DataShape()
+    other.add_entry("value", "STRING", "")
+
+    with pytest.raises(ThingWorxSchemaError):
+        make_shape().merge(other)
+
+
+def test_equality_depends_on_field_order() -> None:
+    reordered = DataShape()
+    reordered.add_entry("value", "NUMBER", "")
+    reordered.add_entry("sensor", "STRING", "")
+
+    assert make_shape() == make_shape()
+    assert make_shape() != reordered
+
+
+def test_len_and_contains() -> None:
+    shape = make_shape()
+
+    assert len(shape) == 2
+    assert "sensor" in shape
+    assert "missing" not in shape
+
+
+def test_iteration_yields_field_names() -> None:
+    assert list(make_shape()) == ["sensor", "value"]
+
+
+def test_entry_getters() -> None:
+    entry = make_shape().get_entry("value")
+
+    assert entry.get_name() == "value"
+    assert entry.get_base_type() == "NUMBER"
+    assert entry.get_description() == "Reading"
+    assert entry.get_aspects() == {}
+    with pytest.raises(KeyError):
+        make_shape().get_entry("missing")
+
+
+def test_set_aspect_is_written_back_with_set_entry() -> None:
+    shape = make_shape()
+    entry = shape.get_entry("sensor")
+
+    entry.set_aspect("isPrimaryKey", "true")
+    assert entry.get_aspect("isPrimaryKey") == "true"
+    assert shape.get_entry("sensor").get_aspect("isPrimaryKey") is None
+
+    shape.set_entry(entry)
+    assert shape.get_entry("sensor").get_aspects() == {"isPrimaryKey": "true"}
+    assert list(shape) == ["sensor", "value"]
+
+
+def test_entry_equality_includes_aspects() -> None:
+    shape = make_shape()
+    entry = shape.get_entry("sensor")
+
+    assert entry == shape.get_entry("sensor")
+    assert entry != shape.get_entry("value")
+
+    entry.set_aspect("isPrimaryKey", "true")
+    assert entry != shape.get_entry("sensor")
+
+
+def test_entry_to_dict() -> None:
+    entry = make_shape().get_entry("value")
+
+    assert entry.to_dict() == {
+        "name": "value",
+        "base_type": "NUMBER",
+        "description": "Reading",
+        "aspects": {},
+    }
+
+
+def set_json_aspect(node: object, field: str, key: str, value: object) -> None:
+    """Sets an aspect on the serialized entry named `field`, wherever it is nested."""
+    if isinstance(node, dict):
+        if node.get("name") == field and "aspects" in node:
+            node["aspects"][key] = value
+        for child in node.values():
+            set_json_aspect(child, field, key, value)
+    elif isinstance(node, list):
+        for child in node:
+            set_json_aspect(child, field, key, value)
+
+
+def test_set_aspect_round_trips_a_boolean_aspect() -> None:
+    serialized = json.loads(make_shape().to_json())
+    set_json_aspect(serialized, "sensor", "isPrimaryKey", True)  # noqa: FBT003
+    shape = DataShape.from_json(json.dumps(serialized))
+    entry = shape.get_entry("sensor")
+    assert entry.get_aspect("isPrimaryKey") == "true"
+
+    entry.set_aspect("isPrimaryKey", entry.get_aspect("isPrimaryKey"))
+
+    assert entry == shape.get_entry("sensor")
+    shape.set_entry(entry)
+    assert shape.to_json() == DataShape.from_json(json.dumps(serialized)).to_json()
+
+
+def test_shape_equality_compares_whole_entries() -> None:
+    shape = make_shape()
+    entry = shape.get_entry("value")
+    entry.set_aspect("isPrimaryKey", "true")
+    shape.set_entry(entry)
+
+    assert shape != make_shape()
+
+    described = DataShape("Readings")
+    described.add_entry("sensor", "STRING", "Sensor name")
+    described.add_entry("value", "NUMBER", "Another description")
+    assert described != make_shape()
@@ -0,0 +1,82 @@
+import pytest
+
+from alwayson import MessageDecoder, ThingWorxProtocolError, TwxMessage
+
+
+@pytest.mark.parametrize("split", [1, 15, 16, -1])
+def test_feed_reassembles_a_split_frame(
+    split: int,
+    request_message: TwxMessage,
+) -> None:
+    msg = request_message
+    frame = msg.to_bytes()
+    decoder = MessageDecoder()
+
+    assert decoder.feed(frame[:split]) == []
+    assert decoder.feed(frame[split:]) == [msg]
+
+
+def test_feed_returns_every_frame_in_a_chunk(
+    request_message: TwxMessage,
+    response_message: TwxMessage,
+    bind_message: TwxMessage,
+) -> None:
+    messages = [request_message, response_message, bind_message]
+    decoder = MessageDecoder()
+
+    decoded = decoder.feed(b"".join(msg.to_bytes() for msg in messages))
+
+    assert decoded == messages
+
+
+def test_feed_rejects_an_unknown_protocol_version() -> None:
+    decoder = MessageDecoder()
+
+    with pytest.raises(ThingWorxProtocolError, match="protocol version 0x02"):
+        decoder.feed(b"\x02" + bytes(14))
+
+
+def test_feed_gives_up_on_a_frame_larger_than_max_frame_size() -> None:
+    decoder = MessageDecoder(max_frame_size=64)
+    # A valid version byte followed by bytes that never decode into a request
+    corrupt = b"\x01\x01" + bytes(13) + b"\xee" * 16
+
+    assert decoder.feed(corrupt) == []
+    with pytest.raises(ThingWorxProtocolError, match="No message in"):
+        decoder.feed(b"\xee" * 64)
+
+
+def test_messages_before_a_corrupt_frame_are_returned_first(
+    response_message: TwxMessage,
+) -> None:
+    msg = response_message
+    decoder = MessageDecoder()
+
+    assert decoder.feed(msg.to_bytes() + b"\x02" + bytes(14)) == [msg]
+    with pytest.raises(ThingWorxProtocolError):
+        decoder.feed(b"")
+
+
+def test_reset_drops_a_partial_frame(
+    request_message: TwxMessage,
+    response_message: TwxMessage,
+) -> None:
+    frame = request_message.to_bytes()
+    decoder = MessageDecoder()
+    decoder.feed(frame[:20])
+
+    decoder.reset()
+
+    assert decoder.feed(response_message.to_bytes()) == [response_message]
+
+
+def test_pending_bytes_counts_undecoded_input(request_message: TwxMessage) -> None:
+    frame = request_message.to_bytes()
+    decoder = MessageDecoder()
+    assert decoder.pending_bytes() == 0
+
+    decoder.feed(frame + frame[:20])
+    assert decoder.pending_bytes() == 20
+
+    decoder.reset()
+    assert decoder.pending_bytes() == 0
@@ -0,0 +1,131 @@
+import copy
+import pickle
+from typing import Callable
+
+import pytest
+
+from alwayson import TwxEvent, TwxProperty, TwxService
+
+
+@pytest.mark.parametrize(
+    "name",
+    ["TemperatureAlarm", "Température_Alerte", "温度警报", "Alarm🔥"],
+)
+def test_event_pickle_round_trip(name: str) -> None:
+    event = TwxEvent(name, "Raised when the limit is exceeded")
+
+    restored = pickle.loads(pickle.dumps(event))  # noqa: S301
+
+    assert restored == event
+    assert restored.get_name() == name
+    assert restored.get_description() == event.get_description()
+
+
+@pytest.mark.parametrize(
+    "name",
+    ["Get Data", "Query?limit=10&offset=0", "Path/To#Section", "100%_done+more"],
+)
+def test_service_pickle_round_trip(name: str) -> None:
+    service = TwxService(name, "Returns the latest readings")
+
+    restored = pickle.loads(pickle.dumps(service))  # noqa: S301
+
+    assert restored == service
+    assert restored.get_name() == name
+    assert restored.to_json() == service.to_json()
+
+
+def test_event_constructor_round_trips_through_json() -> None:
+    event = TwxEvent("TemperatureAlarm", "Raised when the limit is exceeded")
+
+    restored = TwxEvent.from_json(event.to_json())
+
+    assert restored == event
+    assert restored.get_name() == "TemperatureAlarm"
+    assert restored.get_description() == "Raised when the limit is exceeded"
+    data_shape = restored.get_data_shape()
+    assert data_shape is not None
+    assert data_shape.get_name() == "TemperatureAlarm"
+    assert len(data_shape) == 0
+
+
+def test_service_constructor_round_trips_through_json() -> None:
+    service = TwxService("GetData", "Returns the latest readings")
+
+    restored = TwxService.from_json(service.to_json())
+
+    assert restored == service
+    assert restored.get_description() == "Returns the latest readings"
+    assert restored.get_output_base_type() == "NOTHING"
+    inputs = restored.get_input_datashape()
+    assert inputs is not None
+    assert inputs.get_name() == "InputParameters"
+    assert len(inputs) == 0
+
+
+def test_property_constructor_round_trips_through_json() -> None:
+    prop = TwxProperty("Temperature", "number", 0.5)
+
+    restored = TwxProperty.from_json(prop.to_json())
+
+    assert restored == prop
+    assert restored.get_name() == "Temperature"
+    assert restored.get_base_type() == "NUMBER"
+    assert restored.get_push_threshold() == 0.5
+
+
+@pytest.mark.parametrize("threshold", [-0.5, float("nan")])
+def test_set_push_threshold_rejects_invalid_values(threshold: float) -> None:
+    prop = TwxProperty("Temperature", "NUMBER", 0.5)
+
+    with pytest.raises(ValueError, match="non-negative number"):
+        prop.set_push_threshold(threshold)
+    assert prop.get_push_threshold() == 0.5
+
+
+@pytest.mark.parametrize("threshold", [-0.5, float("nan")])
+def test_property_constructor_rejects_invalid_thresholds(threshold: float) -> None:
+    with pytest.raises(ValueError, match="non-negative number"):
+        TwxProperty("Temperature", "NUMBER", threshold)
+
+
+def test_property_constructor_accepts_an_infinite_threshold() -> None:
+    prop = TwxProperty("Temperature", "NUMBER", float("inf"))
+
+    assert prop.get_push_threshold() == float("inf")
+
+
+@pytest.mark.parametrize("threshold", [0.1, float("inf"), 1.7976931348623157e308])
+def test_property_pickle_round_trip(threshold: float) -> None:
+    prop = TwxProperty("Temperature", "NUMBER", threshold)
+
+    restored = pickle.loads(pickle.dumps(prop))  # noqa: S301
+
+    assert restored == prop
+    assert restored.get_push_threshold() == threshold
+
+
+@pytest.mark.parametrize("copier", [copy.copy, copy.deepcopy])
+def test_event_copies_are_independent(copier: Callable[[TwxEvent], TwxEvent]) -> None:
+    original = TwxEvent("TemperatureAlarm", "Raised when the limit is exceeded")
+
+    duplicate = copier(original)
+    duplicate.set_description("Changed")
+
+    assert duplicate is not original
+    assert original.get_description() == "Raised when the limit is exceeded"
+
+
+@pytest.mark.parametrize("copier", [copy.copy, copy.deepcopy])
+def test_property_copies_are_independent(
+    copier: Callable[[TwxProperty], TwxProperty],
+) -> None:
+    original = TwxProperty("Temperature", "NUMBER", 0.5)
+
+    duplicate = copier(original)
+    duplicate.set_push_threshold(2.0)
+    duplicate.set_base_type("INTEGER")
+
+    assert duplicate is not original
+    assert original.get_push_threshold() == 0.5
+    assert original.get_base_type() == "NUMBER"
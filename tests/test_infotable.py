@@ -0,0 +1,253 @@
+import struct
+from typing import Callable
+
+import pytest
+
+from alwayson import InfoTable, Location, ThingWorxSchemaError, TwPrim
+
+
+def sensors(table: InfoTable) -> list:
+    return table.to_pandas_records()["sensor"]
+
+
+def test_filter_rows_keeps_matching_rows(make_table: Callable[[], InfoTable]) -> None:
+    table = make_table()
+
+    filtered = table.filter_rows(lambda row: row["value"] > 1.5)
+
+    assert sensors(filtered) == ["b", "c"]
+    assert table.get_row_count() == 4
+
+
+def test_extend_appends_rows_including_from_itself(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    table = make_table()
+    other = make_table()
+
+    table.extend(other)
+    assert table.get_row_count() == 8
+
+    other.extend(other)
+    assert sensors(other) == ["a", "b", "a", "c"] * 2
+
+
+def test_extend_rejects_a_different_schema(make_table: Callable[[], InfoTable]) -> None:
+    table = make_table()
+    other = InfoTable()
+    other.add_field_definition("sensor", "INTEGER", "")
+
+    with pytest.raises(ThingWorxSchemaError):
+        table.extend(other)
+
+
+def test_byte_size_matches_encoding(make_table: Callable[[], InfoTable]) -> None:
+    table = make_table()
+
+    assert table.byte_size() == len(table.to_bytes())
+
+
+def test_round_trip_through_bytes(make_table: Callable[[], InfoTable]) -> None:
+    table = make_table()
+
+    restored = InfoTable.from_bytes(table.to_bytes())
+
+    assert restored.to_pandas_records() == table.to_pandas_records()
+
+
+def test_duplicate_field_definition_is_rejected(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    table = make_table()
+
+    with pytest.raises(ThingWorxSchemaError):
+        table.add_field_definition("sensor", "STRING", "")
+
+
+def test_location_field_round_trips_through_bytes() -> None:
+    table = InfoTable()
+    table.add_field_definition("position", "LOCATION", "")
+    table.add_row({"position": (40.06498, -75.68393, 12.5)})
+
+    encoded = table.to_bytes()
+    restored = InfoTable.from_bytes(encoded)
+
+    assert restored.to_pandas_records() == {
+        "position": [Location(40.06498, -75.68393, 12.5)],
+    }
+    # ThingWorx writes a LOCATION as big-endian longitude, latitude, elevation
+    assert encoded.endswith(struct.pack(">ddd", -75.68393, 40.06498, 12.5))
+
+
+def test_location_prim_keeps_coordinate_order() -> None:
+    location = Location(40.06498, -75.68393, 12.5)
+
+    prim = TwPrim.location(location)
+
+    assert prim.get_value() == location
+    assert prim.to_bytes()[1:] == struct.pack(">ddd", -75.68393, 40.06498, 12.5)
+
+
+def test_deduplicate_rows_keeps_first_occurrence(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    table = make_table()
+
+    assert table.deduplicate_rows() == 1
+    assert sensors(table) == ["a", "b", "c"]
+
+
+def test_deduplicate_rows_compares_floats_by_value() -> None:
+    table = InfoTable()
+    table.add_field_definition("value", "NUMBER", "")
+    for value in [0.0, -0.0, float("nan"), float("nan")]:
+        table.add_row({"value": value})
+
+    # 0.0 == -0.0, while NaN never equals another NaN
+    assert table.deduplicate_rows() == 1
+    assert table.get_row_count() == 3
+
+
+def test_contains_checks_field_names(make_table: Callable[[], InfoTable]) -> None:
+    table = make_table()
+
+    assert "sensor" in table
+    assert "missing" not in table
+
+
+def test_swap_rows(make_table: Callable[[], InfoTable]) -> None:
+    table = make_table()
+
+    table.swap_rows(0, -1)
+    assert sensors(table) == ["c", "b", "a", "a"]
+
+    with pytest.raises(IndexError):
+        table.swap_rows(0, 4)
+
+
+def test_reverse_rows(make_table: Callable[[], InfoTable]) -> None:
+    table = make_table()
+
+    table.reverse_rows()
+
+    assert sensors(table) == ["c", "a", "b", "a"]
+
+
+def test_filter_rows_rejects_rows_missing_a_later_field(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    table = make_table()
+    table.add_field_definition("unit", "STRING", "")
+
+    with pytest.raises(ThingWorxSchemaError, match="Row 0 has no value for field unit"):
+        table.filter_rows(lambda _row: True)
+
+
+def test_to_pandas_records_is_column_oriented(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    records = make_table().to_pandas_records()
+
+    assert records == {
+        "sensor": ["a", "b", "a", "c"],
+        "value": [1.0, 2.0, 1.0, 3.0],
+    }
+
+
+def test_to_pandas_records_rejects_rows_missing_a_later_field(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    table = make_table()
+    table.add_field_definition("unit", "STRING", "")
+
+    with pytest.raises(ThingWorxSchemaError, match="Row 0 has no value for field unit"):
+        table.to_pandas_records()
+
+
+def test_dump_lists_every_row(make_table: Callable[[], InfoTable]) -> None:
+    dump = make_table().dump()
+
+    assert dump.startswith('InfoTable "Readings" (2 fields, 4 rows)')
+    assert '  [1] sensor="b", value=2' in dump
+
+
+def test_dump_marks_fields_missing_from_a_row(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    table = make_table()
+    table.add_field_definition("unit", "STRING", "")
+
+    dump = table.dump()
+
+    assert '  [0] sensor="a", value=1, unit=<missing>' in dump
+
+
+def test_project_selects_fields_in_order(make_table: Callable[[], InfoTable]) -> None:
+    projected = make_table().project(["value", "sensor"])
+
+    assert list(projected.to_pandas_records()) == ["value", "sensor"]
+    assert projected.get_row_count() == 4
+
+
+def test_project_rejects_repeated_and_unknown_fields(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    table = make_table()
+
+    with pytest.raises(ValueError, match="more than once"):
+        table.project(["sensor", "sensor"])
+    with pytest.raises(KeyError):
+        table.project(["missing"])
+
+
+def test_nothing_is_not_a_field_type() -> None:
+    with pytest.raises(ThingWorxSchemaError):
+        InfoTable().add_field_definition("empty", "NOTHING", "")
+
+
+def test_to_bytes_into_fills_a_preallocated_buffer(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    table = make_table()
+    buffer = bytearray(table.byte_size())
+
+    written = table.to_bytes_into(buffer)
+
+    assert written == len(buffer)
+    assert bytes(buffer) == table.to_bytes()
+
+
+def test_to_bytes_into_writes_at_offset(make_table: Callable[[], InfoTable]) -> None:
+    table = make_table()
+    buffer = bytearray(b"head" + bytes(table.byte_size()) + b"tail")
+
+    written = table.to_bytes_into(buffer, offset=4)
+
+    assert bytes(buffer) == b"head" + table.to_bytes() + b"tail"
+    assert written == table.byte_size()
+
+
+def test_to_bytes_into_rejects_a_short_buffer(
+    make_table: Callable[[], InfoTable],
+) -> None:
+    table = make_table()
+    buffer = bytearray(table.byte_size())
+
+    with pytest.raises(ValueError, match="Buffer too small"):
+        table.to_bytes_into(buffer, offset=1)
+    assert buffer == bytearray(table.byte_size())
+
+
+def test_infotable_prim_value_is_an_infotable() -> None:
+    value = TwPrim.infotable_empty().get_value()
+
+    assert isinstance(value, InfoTable)
+    assert value.get_row_count() == 0
+
+
+def test_location_is_immutable_and_hashable() -> None:
+    location = Location(40.06498, -75.68393, 12.5)
+
+    with pytest.raises(AttributeError):
+        location.latitude = 0.0  # type: ignore[misc]
+    assert {location, Location(40.06498, -75.68393, 12.5)} == {location}
@@ -0,0 +1,102 @@
+import json
+import pickle
+
+import pytest
+
+from alwayson import ThingWorxProtocolError, TwPrim, TwxMessage
+
+
+def test_build_bind_round_trip_keeps_thing_name() -> None:
+    msg = TwxMessage.build_bind(7, "EdgeThing_01")
+
+    parsed = TwxMessage.from_bytes(msg.to_bytes())
+
+    assert parsed.is_bind()
+    assert parsed.get_request_id() == 7
+    assert parsed == msg
+    assert b"EdgeThing_01" in parsed.to_bytes()
+
+
+def test_pickle_round_trip(message: TwxMessage) -> None:
+    restored = pickle.loads(pickle.dumps(message))  # noqa: S301
+
+    assert restored == message
+    assert restored.get_message_type() == message.get_message_type()
+    assert restored.to_bytes() == message.to_bytes()
+
+
+def test_body_bytes_decode_as_twprim(
+    request_message: TwxMessage,
+    response_message: TwxMessage,
+) -> None:
+    assert TwPrim.from_bytes(request_message.get_body_bytes()).get_value() == "hello"
+    assert TwPrim.from_bytes(response_message.get_body_bytes()).get_value() == 1.5
+    assert request_message.get_body_bytes() == TwPrim.string("hello").to_bytes()
+
+
+@pytest.mark.parametrize("fixture", ["auth_message", "bind_message"])
+def test_body_bytes_need_a_payload(
+    fixture: str,
+    request: pytest.FixtureRequest,
+) -> None:
+    message = request.getfixturevalue(fixture)
+
+    with pytest.raises(ThingWorxProtocolError, match="carry no payload"):
+        message.get_body_bytes()
+
+
+def test_byte_size_matches_encoding(message: TwxMessage) -> None:
+    assert message.byte_size() == len(message.to_bytes())
+
+
+def test_byte_size_matches_encoding_with_long_strings() -> None:
+    # Strings of 128 bytes or more switch to a 4-byte length prefix
+    msg = TwxMessage.build_request(
+        5,
+        "PUT",
+        f"/Thingworx/Things/{'T' * 200}/Properties/temperature",
+        {"X-Trace": "v" * 300},
+        TwPrim.string("s" * 150).to_bytes(),
+    )
+
+    assert msg.byte_size() == len(msg.to_bytes())
+
+
+@pytest.mark.parametrize(
+    ("method", "uri", "expected"),
+    [
+        ("POST", "/Thingworx/Things/EdgeThing/Services/KeepAlive", True),
+        ("GET", "/Thingworx/Things/EdgeThing/Services/KeepAlive", False),
+        ("POST", "/Thingworx/Things/EdgeThing/Services/GetData", False),
+        ("PUT", "/Thingworx/Things/EdgeThing/Properties/KeepAlive", False),
+    ],
+)
+def test_is_keep_alive_follows_the_url_convention(
+    method: str,
+    uri: str,
+    expected: bool,  # noqa: FBT001
+) -> None:
+    msg = TwxMessage.build_request(6, method, uri)
+
+    assert msg.is_keep_alive() is expected
+
+
+@pytest.mark.parametrize(
+    "fixture",
+    ["response_message", "auth_message", "bind_message"],
+)
+def test_only_requests_are_keep_alives(
+    fixture: str,
+    request: pytest.FixtureRequest,
+) -> None:
+    assert not request.getfixturevalue(fixture).is_keep_alive()
+
+
+def test_to_json_body_is_the_payload_hex(
+    request_message: TwxMessage,
+    auth_message: TwxMessage,
+) -> None:
+    body = json.loads(request_message.to_json())["body"]
+
+    assert body == request_message.get_body_bytes().hex()
+    assert json.loads(auth_message.to_json())["body"] is None
@@ -0,0 +1,16 @@
+import pytest
+
+from alwayson import ThingWorxCodecError, TwPrim, decode_twprim
+
+
+def test_decode_twprim_reads_a_value_without_its_type_byte() -> None:
+    data = TwPrim.number(1.5).to_bytes()[1:]
+
+    assert decode_twprim("NUMBER", data).get_value() == 1.5
+
+
+def test_decode_twprim_rejects_trailing_bytes() -> None:
+    data = TwPrim.number(1.5).to_bytes()[1:] + b"\x00"
+
+    with pytest.raises(ThingWorxCodecError, match="1 bytes left"):
+        decode_twprim("NUMBER", data)